@@ -10,7 +10,9 @@ fn main() {
     println!("Describe a date or time:");
     loop {
         buffer.clear();
-        stdin.read_line(&mut buffer).unwrap();
+        if stdin.read_line(&mut buffer).unwrap() == 0 {
+            break;
+        }
         let now = Local::now().naive_local();
         let result = match human_date_parser::from_human_time(&buffer, now) {
             Ok(time) => time,
@@ -29,6 +31,7 @@ fn main() {
             }
             ParseResult::Date(date) => println!("Date: {date}\n"),
             ParseResult::Time(time) => println!("Time: {time}\n"),
+            ParseResult::Range(range) => println!("Range: {range}\n"),
         };
     }
 }
@@ -0,0 +1,57 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use chrono::{NaiveDate, NaiveDateTime};
+
+/// A date registered under a name with [`Parser::anchor`](crate::Parser::anchor), like "release
+/// day" or "payday". It can be used anywhere "today" or "tomorrow" can, as in "2 days before sprint
+/// end at 10:00".
+#[derive(Clone)]
+pub enum Anchor {
+    /// Always the same date.
+    Fixed(NaiveDate),
+    /// A date computed from the current date and time each time the input is parsed.
+    Computed(Arc<dyn Fn(NaiveDateTime) -> NaiveDate + Send + Sync>),
+}
+
+impl Anchor {
+    /// Creates an anchor that computes its date from the current date and time.
+    pub fn computed(compute: impl Fn(NaiveDateTime) -> NaiveDate + Send + Sync + 'static) -> Self {
+        Anchor::Computed(Arc::new(compute))
+    }
+
+    pub(crate) fn resolve(&self, now: NaiveDateTime) -> NaiveDate {
+        match self {
+            Anchor::Fixed(date) => *date,
+            Anchor::Computed(compute) => compute(now),
+        }
+    }
+}
+
+impl From<NaiveDate> for Anchor {
+    fn from(date: NaiveDate) -> Self {
+        Anchor::Fixed(date)
+    }
+}
+
+impl Debug for Anchor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Anchor::Fixed(date) => f.debug_tuple("Fixed").field(date).finish(),
+            Anchor::Computed(_) => f.write_str("Computed(..)"),
+        }
+    }
+}
+
+/// Computed anchors are compared by identity, as closures can not be compared by value.
+impl PartialEq for Anchor {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Anchor::Fixed(a), Anchor::Fixed(b)) => a == b,
+            (Anchor::Computed(a), Anchor::Computed(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Anchor {}
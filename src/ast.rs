@@ -2,20 +2,57 @@ use chrono::Month;
 use pest_consume::{match_nodes, Error, Parser as ConsumeParser};
 use pest_derive::Parser;
 
-use crate::{InternalError, ParseError};
+use crate::{InternalError, ParseError, SyntaxError};
 
 type ParserResult<T> = std::result::Result<T, Error<Rule>>;
 type Node<'i> = pest_consume::Node<'i, Rule, ()>;
 
+/// Builds the syntax tree of a date or time, which has to span all of `str`.
 pub fn build_ast_from(str: &str) -> Result<HumanTime, ParseError> {
-    let result = DateTimeParser::parse(Rule::HumanTime, &str)
+    let result = DateTimeParser::parse(Rule::HumanTimeInput, str)
         .and_then(|result| result.single())
-        .map_err(|_| ParseError::InvalidFormat)?;
+        .map_err(|error| ParseError::InvalidFormat(SyntaxError::from_pest(str, error)))?;
 
-    DateTimeParser::HumanTime(result)
+    DateTimeParser::HumanTimeInput(result)
         .map_err(|_| ParseError::InternalError(InternalError::FailedToBuildAst))
 }
 
+/// Builds the syntax tree of the longest start of `str` that can be parsed, and returns it
+/// together with where that start ends.
+pub fn build_prefix_ast_from(str: &str) -> Result<(HumanTime, usize), ParseError> {
+    let result = DateTimeParser::parse(Rule::HumanTime, str)
+        .and_then(|result| result.single())
+        .map_err(|error| ParseError::InvalidFormat(SyntaxError::from_pest(str, error)))?;
+    let end = result.as_span().end();
+
+    let ast = DateTimeParser::HumanTime(result)
+        .map_err(|_| ParseError::InternalError(InternalError::FailedToBuildAst))?;
+    Ok((ast, end))
+}
+
+/// Builds the syntax tree of a recurrence, which has to span all of `str`.
+pub fn build_recurrence_ast_from(str: &str) -> Result<Recurrence, ParseError> {
+    let result = DateTimeParser::parse(Rule::HumanRecurrence, str)
+        .and_then(|result| result.single())
+        .map_err(|error| ParseError::InvalidFormat(SyntaxError::from_pest(str, error)))?;
+
+    DateTimeParser::HumanRecurrence(result)
+        .map_err(|_| ParseError::InternalError(InternalError::FailedToBuildAst))
+}
+
+/// Builds the syntax tree of a duration like "2 hours and 5 minutes" at the start of `str`, and
+/// returns it together with where it ends.
+pub fn build_duration_ast_from(str: &str) -> Result<(Duration, usize), ParseError> {
+    let result = DateTimeParser::parse(Rule::Duration, str)
+        .and_then(|result| result.single())
+        .map_err(|error| ParseError::InvalidFormat(SyntaxError::from_pest(str, error)))?;
+    let end = result.as_span().end();
+
+    let ast = DateTimeParser::Duration(result)
+        .map_err(|_| ParseError::InternalError(InternalError::FailedToBuildAst))?;
+    Ok((ast, end))
+}
+
 #[derive(Parser)]
 #[grammar = "date_time.pest"]
 pub(crate) struct DateTimeParser;
@@ -23,20 +60,197 @@ pub(crate) struct DateTimeParser;
 #[pest_consume::parser]
 impl DateTimeParser {
     pub(crate) fn HumanTime(input: Node) -> ParserResult<HumanTime> {
+        Ok(match_nodes!(input.into_children();
+            [Range(r)] => HumanTime::Range(r),
+            [SingleTime(st)] => st,
+        ))
+    }
+
+    pub(crate) fn HumanTimeInput(input: Node) -> ParserResult<HumanTime> {
+        Ok(match_nodes!(input.into_children();
+            [HumanTime(ht), EOI(_)] => ht,
+        ))
+    }
+
+    pub(crate) fn HumanRecurrence(input: Node) -> ParserResult<Recurrence> {
+        Ok(match_nodes!(input.into_children();
+            [Recurrence(r), EOI(_)] => r,
+        ))
+    }
+
+    fn EOI(input: Node) -> ParserResult<()> {
+        Ok(())
+    }
+
+    fn Recurrence(input: Node) -> ParserResult<Recurrence> {
+        Ok(match_nodes!(input.into_children();
+            [RecurrenceRule(rule)] => Recurrence { rule, time: None, end: None },
+            [RecurrenceRule(rule), RecurrenceTime(t)] => Recurrence { rule, time: Some(t), end: None },
+            [RecurrenceRule(rule), RecurrenceEnd(e)] => Recurrence { rule, time: None, end: Some(e) },
+            [RecurrenceRule(rule), RecurrenceTime(t), RecurrenceEnd(e)] => {
+                Recurrence { rule, time: Some(t), end: Some(e) }
+            },
+        ))
+    }
+
+    fn RecurrenceRule(input: Node) -> ParserResult<RecurrenceRule> {
+        Ok(match_nodes!(input.into_children();
+            [MonthWeekdayRule(r)] => r,
+            [MonthDayRule(r)] => r,
+            [WeekdaysRule(r)] => r,
+            [IntervalRule(r)] => r,
+        ))
+    }
+
+    fn MonthDayRule(input: Node) -> ParserResult<RecurrenceRule> {
+        Ok(match_nodes!(input.into_children();
+            [DayOrdinal(day)] => RecurrenceRule::MonthDay(1, day),
+            [DayOrdinal(day), RecurrenceInterval(i)] => RecurrenceRule::MonthDay(i, day),
+            [RecurrenceInterval(i), DayOrdinal(day)] => RecurrenceRule::MonthDay(i, day),
+        ))
+    }
+
+    fn MonthWeekdayRule(input: Node) -> ParserResult<RecurrenceRule> {
+        Ok(match_nodes!(input.into_children();
+            [WeekdayOrdinal(o), Weekday(wd)] => RecurrenceRule::MonthWeekday(1, o, wd),
+            [WeekdayOrdinal(o), Weekday(wd), RecurrenceInterval(i)] => {
+                RecurrenceRule::MonthWeekday(i, o, wd)
+            },
+            [RecurrenceInterval(i), WeekdayOrdinal(o), Weekday(wd)] => {
+                RecurrenceRule::MonthWeekday(i, o, wd)
+            },
+        ))
+    }
+
+    fn WeekdaysRule(input: Node) -> ParserResult<RecurrenceRule> {
+        Ok(match_nodes!(input.into_children();
+            [WeekdayList(wds)] => RecurrenceRule::Weekdays(1, wds),
+            [RecurrenceInterval(i), WeekdayList(wds)] => RecurrenceRule::Weekdays(i, wds),
+        ))
+    }
+
+    fn IntervalRule(input: Node) -> ParserResult<RecurrenceRule> {
+        Ok(match_nodes!(input.into_children();
+            [TimeUnit(u)] => RecurrenceRule::Interval(1, u),
+            [RecurrenceInterval(i), TimeUnit(u)] => RecurrenceRule::Interval(i, u),
+            [Frequency(u)] => RecurrenceRule::Interval(1, u),
+        ))
+    }
+
+    fn RecurrenceInterval(input: Node) -> ParserResult<u32> {
+        Ok(match_nodes!(input.into_children();
+            [Other(_)] => 2,
+            [Num(n)] => n,
+        ))
+    }
+
+    fn Other(input: Node) -> ParserResult<()> {
+        Ok(())
+    }
+
+    fn WeekdayList(input: Node) -> ParserResult<Vec<WeekdayItem>> {
+        Ok(match_nodes!(input.into_children();
+            [WeekdayItem(items)..] => items.collect(),
+        ))
+    }
+
+    fn WeekdayItem(input: Node) -> ParserResult<WeekdayItem> {
+        Ok(match_nodes!(input.into_children();
+            [Workdays(_)] => WeekdayItem::Workdays,
+            [Weekday(wd)] => WeekdayItem::Weekday(wd),
+        ))
+    }
+
+    fn Workdays(input: Node) -> ParserResult<()> {
+        Ok(())
+    }
+
+    fn DayOrdinal(input: Node) -> ParserResult<u32> {
+        Ok(match_nodes!(input.into_children();
+            [Num(n)] => n,
+        ))
+    }
+
+    fn WeekdayOrdinal(input: Node) -> ParserResult<i32> {
+        match input.as_str() {
+            "first" | "1st" => Ok(1),
+            "second" | "2nd" => Ok(2),
+            "third" | "3rd" => Ok(3),
+            "fourth" | "4th" => Ok(4),
+            "fifth" | "5th" => Ok(5),
+            "last" => Ok(-1),
+            _ => Err(input.error("Unreachable")),
+        }
+    }
+
+    fn Frequency(input: Node) -> ParserResult<TimeUnit> {
+        match input.as_str() {
+            "yearly" | "annually" => Ok(TimeUnit::Year),
+            "quarterly" => Ok(TimeUnit::Quarter),
+            "monthly" => Ok(TimeUnit::Month),
+            "weekly" => Ok(TimeUnit::Week),
+            "daily" => Ok(TimeUnit::Day),
+            "hourly" => Ok(TimeUnit::Hour),
+            _ => Err(input.error("Unreachable")),
+        }
+    }
+
+    fn RecurrenceTime(input: Node) -> ParserResult<Time> {
+        Ok(match_nodes!(input.into_children();
+            [Time(t)] => t,
+            [BareHour(h)] => Time::HourMinute(h, 0),
+        ))
+    }
+
+    fn RecurrenceEnd(input: Node) -> ParserResult<RecurrenceEnd> {
+        Ok(match_nodes!(input.into_children();
+            [SingleTime(st)] => RecurrenceEnd::Until(Box::new(st)),
+            [Num(n)] => RecurrenceEnd::Count(n),
+        ))
+    }
+
+    fn SingleTime(input: Node) -> ParserResult<HumanTime> {
         Ok(match_nodes!(input.into_children();
             [DateTime(dt)] => HumanTime::DateTime(dt),
             [Date(d)] => HumanTime::Date(d),
             [Time(t)] => HumanTime::Time(t),
+            [BareHour(h)] => HumanTime::Time(Time::HourMinute(h, 0)),
             [In(i)] => HumanTime::In(i),
             [Ago(a)] => HumanTime::Ago(a),
             [Now(_)] => HumanTime::Now,
         ))
     }
 
+    fn Range(input: Node) -> ParserResult<Range> {
+        Ok(match_nodes!(input.into_children();
+            [Month_Name(m), Num(from), Num(to), YearNum(y)] => Range::of_days(from, to, m, Some(y)),
+            [Month_Name(m), Num(from), Num(to)] => Range::of_days(from, to, m, None),
+            [Num(from), Num(to), Month_Name(m), YearNum(y)] => Range::of_days(from, to, m, Some(y)),
+            [Num(from), Num(to), Month_Name(m)] => Range::of_days(from, to, m, None),
+            [RangeBound(start), RangeBound(end)] => Range { start, end },
+        ))
+    }
+
+    fn RangeBound(input: Node) -> ParserResult<RangeBound> {
+        Ok(match_nodes!(input.into_children();
+            [SingleTime(st)] => RangeBound::HumanTime(Box::new(st)),
+            [BareHour(h)] => RangeBound::Hour(h),
+        ))
+    }
+
+    fn BareHour(input: Node) -> ParserResult<u32> {
+        Ok(match_nodes!(input.into_children();
+            [Num(h)] => h,
+        ))
+    }
+
     fn DateTime(input: Node) -> ParserResult<DateTime> {
         Ok(match_nodes!(input.into_children();
             [Date(date), Time(time)] => DateTime{ date, time },
+            [Date(date), BareHour(h)] => DateTime{ date, time: Time::HourMinute(h, 0) },
             [Time(time), Date(date)] => DateTime{ date, time },
+            [Date(date), PartOfDay(time)] => DateTime{ date, time },
+            [PartOfDay(time)] => DateTime{ date: Date::Today, time },
         ))
     }
 
@@ -46,22 +260,116 @@ impl DateTimeParser {
         ))
     }
 
+    fn DottedDate(input: Node) -> ParserResult<Date> {
+        Ok(match_nodes!(input.into_children();
+            [Num(day), Num(month), Num(year)] => Date::IsoDate(IsoDate { year, month, day }),
+            [Num(day), Num(month)] => Date::DottedDayMonth(day, month),
+        ))
+    }
+
+    fn PartOfDay(input: Node) -> ParserResult<Time> {
+        let hour = match input.children().next().map(|rule| rule.as_rule()) {
+            Some(Rule::Morning) => 9,
+            Some(Rule::Noon) => 12,
+            Some(Rule::Afternoon) => 15,
+            Some(Rule::Evening) => 18,
+            Some(Rule::Night) => 21,
+            _ => unreachable!(),
+        };
+        Ok(Time::HourMinute(hour, 0))
+    }
+
     fn Date(input: Node) -> ParserResult<Date> {
         Ok(match_nodes!(input.into_children();
+            [Day(_), OffsetDirection(o), Date(date)] => {
+                Date::Offset(Duration(vec![Quantifier::Day(1)]), o, Box::new(date))
+            },
+            [Duration(d), OffsetDirection(o), Date(date)] => Date::Offset(d, o, Box::new(date)),
+            [OffsetDirection(o), Date(date)] => {
+                Date::Offset(Duration(vec![Quantifier::Day(1)]), o, Box::new(date))
+            },
+            [Named(name), YearNum(y)] => Date::Named(name, Some(y)),
+            [RelativeSpecifier(r), Named(name)] => Date::RelativeNamed(r, name),
+            [Named(name), RelativeSpecifier(r)] => Date::RelativeNamed(r, name),
+            [Named(name)] => Date::Named(name, None),
             [Today(_)] => Date::Today,
             [Tomorrow(_)] => Date::Tomorrow,
             [Overmorrow(_)] => Date::Overmorrow,
             [Yesterday(_)] => Date::Yesterday,
             [IsoDate(iso)] => Date::IsoDate(iso),
+            [DottedDate(date)] => date,
+            [QuarterOfYear(q), Num(y)] => Date::QuarterYear(q, y),
+            [QuarterOfYear(q)] => Date::Quarter(q),
             [Num(d), Month_Name(m), Num(y)] => Date::DayMonthYear(d, m, y),
             [Num(d), Month_Name(m)] => Date::DayMonth(d, m),
+            [Month_Name(m), YearNum(y)] => Date::MonthYear(m, y),
+            [YearNum(y)] => Date::Year(y),
+            [Month_Name(m)] => Date::Month(m),
+            [RelativeSpecifier(r), Month_Name(m)] => Date::RelativeMonth(r, m),
+            [Month_Name(m), RelativeSpecifier(r)] => Date::RelativeMonth(r, m),
+            [StartOf(_), Week(_)] => Date::StartOfWeek(None),
+            [StartOf(_), RelativeSpecifier(r), Week(_)] => Date::StartOfWeek(Some(r)),
+            [EndOf(_), Week(_)] => Date::EndOfWeek(None),
+            [EndOf(_), RelativeSpecifier(r), Week(_)] => Date::EndOfWeek(Some(r)),
+            [Weekend(_)] => Date::Weekend(None),
+            [RelativeSpecifier(r), Weekend(_)] => Date::Weekend(Some(r)),
+            [Weekend(_), RelativeSpecifier(r)] => Date::Weekend(Some(r)),
+            [Workweek(_)] => Date::Workweek(None),
+            [RelativeSpecifier(r), Workweek(_)] => Date::Workweek(Some(r)),
+            [Workweek(_), RelativeSpecifier(r)] => Date::Workweek(Some(r)),
             [RelativeSpecifier(r), Week(_), Weekday(wd)] => Date::RelativeWeekWeekday(r, wd),
+            [Weekday(wd), RelativeSpecifier(r), Week(_)] => Date::RelativeWeekWeekday(r, wd),
             [RelativeSpecifier(r), TimeUnit(tu)] => Date::RelativeTimeUnit(r, tu),
+            [TimeUnit(tu), RelativeSpecifier(r)] => Date::RelativeTimeUnit(r, tu),
             [RelativeSpecifier(r), Weekday(wd)] => Date::RelativeWeekday(r, wd),
+            [Weekday(wd), RelativeSpecifier(r)] => Date::RelativeWeekday(r, wd),
             [Weekday(wd)] => Date::UpcomingWeekday(wd),
         ))
     }
 
+    fn Named(input: Node) -> ParserResult<String> {
+        Ok(match_nodes!(input.into_children();
+            [NameKey(name)] => name,
+        ))
+    }
+
+    fn NameKey(input: Node) -> ParserResult<String> {
+        Ok(input.as_str().to_string())
+    }
+
+    fn OffsetDirection(input: Node) -> ParserResult<OffsetDirection> {
+        Ok(match_nodes!(input.into_children();
+            [After(_)] => OffsetDirection::After,
+            [Before(_)] => OffsetDirection::Before,
+        ))
+    }
+
+    fn After(input: Node) -> ParserResult<()> {
+        Ok(())
+    }
+
+    fn Before(input: Node) -> ParserResult<()> {
+        Ok(())
+    }
+
+    fn Day(input: Node) -> ParserResult<()> {
+        Ok(())
+    }
+
+    fn YearNum(input: Node) -> ParserResult<u32> {
+        input.as_str().parse::<u32>().map_err(|e| input.error(e))
+    }
+
+    fn QuarterOfYear(input: Node) -> ParserResult<u32> {
+        Ok(match_nodes!(input.into_children();
+            [QuarterNum(q)] => q,
+        ))
+    }
+
+    fn QuarterNum(input: Node) -> ParserResult<u32> {
+        input.as_str().parse::<u32>().map_err(|e| input.error(e))
+    }
+
     fn Week(input: Node) -> ParserResult<Week> {
         Ok(Week {})
     }
@@ -69,7 +377,9 @@ impl DateTimeParser {
     fn Ago(input: Node) -> ParserResult<Ago> {
         Ok(match_nodes!(input.into_children();
             [Duration(d)] => Ago::AgoFromNow(d),
-            [Duration(d), HumanTime(ht)] => Ago::AgoFromTime(d, Box::new(ht)),
+            [Duration(d), SingleTime(ht)] => Ago::AgoFromTime(d, Box::new(ht)),
+            [Before(_), Duration(d)] => Ago::AgoFromNow(d),
+            [Before(_), Duration(d), SingleTime(ht)] => Ago::AgoFromTime(d, Box::new(ht)),
         ))
     }
 
@@ -77,6 +387,22 @@ impl DateTimeParser {
         Ok(Now {})
     }
 
+    fn StartOf(input: Node) -> ParserResult<StartOf> {
+        Ok(StartOf {})
+    }
+
+    fn EndOf(input: Node) -> ParserResult<EndOf> {
+        Ok(EndOf {})
+    }
+
+    fn Weekend(input: Node) -> ParserResult<Weekend> {
+        Ok(Weekend {})
+    }
+
+    fn Workweek(input: Node) -> ParserResult<Workweek> {
+        Ok(Workweek {})
+    }
+
     fn Today(input: Node) -> ParserResult<Today> {
         Ok(Today {})
     }
@@ -95,6 +421,7 @@ impl DateTimeParser {
 
     fn Time(input: Node) -> ParserResult<Time> {
         Ok(match_nodes!(input.into_children();
+            [Num(h)] => Time::HourMinute(h, 0),
             [Num(h), Num(m)] => Time::HourMinute(h, m),
             [Num(h), Num(m), Num(s)] => Time::HourMinuteSecond(h, m, s),
         ))
@@ -117,8 +444,10 @@ impl DateTimeParser {
         Ok(match_nodes!(input.into_children();
             [TimeUnit(u)] => match u {
                 TimeUnit::Year => Quantifier::Year(1),
+                TimeUnit::Quarter => Quantifier::Quarter(1),
                 TimeUnit::Month => Quantifier::Month(1),
                 TimeUnit::Week => Quantifier::Week(1),
+                TimeUnit::BusinessDay => Quantifier::BusinessDay(1),
                 TimeUnit::Day => Quantifier::Day(1),
                 TimeUnit::Hour => Quantifier::Hour(1),
                 TimeUnit::Minute => Quantifier::Minute(1),
@@ -155,8 +484,10 @@ impl DateTimeParser {
         Ok(match_nodes!(input.into_children();
             [Num(n), TimeUnit(u)] => match u {
                 TimeUnit::Year => Quantifier::Year(n),
+                TimeUnit::Quarter => Quantifier::Quarter(n),
                 TimeUnit::Month => Quantifier::Month(n),
                 TimeUnit::Week => Quantifier::Week(n),
+                TimeUnit::BusinessDay => Quantifier::BusinessDay(n),
                 TimeUnit::Day => Quantifier::Day(n),
                 TimeUnit::Hour => Quantifier::Hour(n),
                 TimeUnit::Minute => Quantifier::Minute(n),
@@ -169,8 +500,10 @@ impl DateTimeParser {
         if let Some(rule) = input.children().next() {
             Ok(match rule.as_rule() {
                 Rule::Year => TimeUnit::Year,
+                Rule::Quarter => TimeUnit::Quarter,
                 Rule::Month => TimeUnit::Month,
                 Rule::Week => TimeUnit::Week,
+                Rule::BusinessDay => TimeUnit::BusinessDay,
                 Rule::Day => TimeUnit::Day,
                 Rule::Hour => TimeUnit::Hour,
                 Rule::Minute => TimeUnit::Minute,
@@ -222,7 +555,7 @@ impl DateTimeParser {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum HumanTime {
     DateTime(DateTime),
     Date(Date),
@@ -230,36 +563,137 @@ pub enum HumanTime {
     In(In),
     Ago(Ago),
     Now,
+    Range(Range),
+}
+
+#[derive(Debug, Clone)]
+pub struct Range {
+    pub start: RangeBound,
+    pub end: RangeBound,
+}
+
+impl Range {
+    /// Builds the range for inputs like "March 3-7", where both bounds share month and year.
+    fn of_days(from: u32, to: u32, month: Month, year: Option<u32>) -> Self {
+        let bound = |day| {
+            let date = match year {
+                Some(year) => Date::DayMonthYear(day, month, year),
+                None => Date::DayMonth(day, month),
+            };
+            RangeBound::HumanTime(Box::new(HumanTime::Date(date)))
+        };
+
+        Range {
+            start: bound(from),
+            end: bound(to),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RangeBound {
+    HumanTime(Box<HumanTime>),
+    /// A bare number like the `9` in "between 9 and 5", read as a full hour.
+    Hour(u32),
+}
+
+#[derive(Debug)]
+pub struct Recurrence {
+    pub rule: RecurrenceRule,
+    pub time: Option<Time>,
+    pub end: Option<RecurrenceEnd>,
 }
 
+/// The repeating part of a recurrence. The first value of every variant is the interval, so `2`
+/// for "every other week".
 #[derive(Debug)]
+pub enum RecurrenceRule {
+    Interval(u32, TimeUnit),
+    Weekdays(u32, Vec<WeekdayItem>),
+    MonthDay(u32, u32),
+    /// The ordinal is counted from the start of the month, with `-1` standing for the last one.
+    MonthWeekday(u32, i32, Weekday),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum WeekdayItem {
+    Weekday(Weekday),
+    /// The days that are not part of the weekend, which depends on [`crate::ParseOptions`].
+    Workdays,
+}
+
+#[derive(Debug)]
+pub enum RecurrenceEnd {
+    Until(Box<HumanTime>),
+    Count(u32),
+}
+
+#[derive(Debug, Clone)]
 pub struct DateTime {
     pub date: Date,
     pub time: Time,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IsoDate {
     pub year: u32,
     pub month: u32,
     pub day: u32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Date {
     Today,
     Tomorrow,
     Overmorrow,
     Yesterday,
     IsoDate(IsoDate),
+    Quarter(u32),
+    QuarterYear(u32, u32),
     DayMonthYear(u32, Month, u32),
     DayMonth(u32, Month),
+    /// A day and month written as numbers, like "24.12.". The month is only checked when the date
+    /// is resolved, like the day.
+    DottedDayMonth(u32, u32),
+    MonthYear(Month, u32),
+    Year(u32),
+    Month(Month),
+    RelativeMonth(RelativeSpecifier, Month),
+    /// The first day of a week, like "the start of next week". Without a specifier it is the
+    /// current week.
+    StartOfWeek(Option<RelativeSpecifier>),
+    /// The last day of a week, like "the end of the week".
+    EndOfWeek(Option<RelativeSpecifier>),
+    /// The weekend days, by default Saturday and Sunday. Without a specifier it is the current or
+    /// upcoming weekend.
+    Weekend(Option<RelativeSpecifier>),
+    /// The days of the week that are not part of the weekend, like "this workweek".
+    Workweek(Option<RelativeSpecifier>),
     RelativeWeekWeekday(RelativeSpecifier, Weekday),
     RelativeTimeUnit(RelativeSpecifier, TimeUnit),
     RelativeWeekday(RelativeSpecifier, Weekday),
     UpcomingWeekday(Weekday),
+    /// A holiday looked up by name, optionally in a given year.
+    Named(String, Option<u32>),
+    RelativeNamed(RelativeSpecifier, String),
+    /// A duration before or after another date, like "the day after tomorrow".
+    Offset(Duration, OffsetDirection, Box<Date>),
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum OffsetDirection {
+    After,
+    Before,
+}
+
+#[derive(Debug)]
+struct StartOf;
+#[derive(Debug)]
+struct EndOf;
+#[derive(Debug)]
+struct Weekend;
+#[derive(Debug)]
+struct Workweek;
 #[derive(Debug)]
 struct Today;
 #[derive(Debug)]
@@ -269,28 +703,28 @@ struct Yesterday;
 #[derive(Debug)]
 struct Overmorrow;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Time {
     HourMinute(u32, u32),
     HourMinuteSecond(u32, u32, u32),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct In(pub Duration);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Ago {
     AgoFromNow(Duration),
     AgoFromTime(Duration, Box<HumanTime>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Duration(pub Vec<Quantifier>);
 
 #[derive(Debug)]
 struct Now;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum RelativeSpecifier {
     This,
     Next,
@@ -304,29 +738,34 @@ struct Next;
 #[derive(Debug)]
 struct Last;
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Quantifier {
     Year(u32),
+    Quarter(u32),
     Month(u32),
     Week(u32),
+    /// Days the business calendar counts as working days.
+    BusinessDay(u32),
     Day(u32),
     Hour(u32),
     Minute(u32),
     Second(u32),
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum TimeUnit {
     Year,
+    Quarter,
     Month,
     Week,
+    BusinessDay,
     Day,
     Hour,
     Minute,
     Second,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum Weekday {
     Monday,
     Tuesday,
@@ -0,0 +1,149 @@
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Weekday};
+
+use crate::{Direction, HolidayCalendar, ProcessingError};
+
+/// A business calendar can go this many days in a row without a business day before it is
+/// considered broken.
+const MAX_DAYS_OFF: u32 = 366;
+
+/// Decides which days count as business days, for inputs like "in 3 business days" or "next
+/// working day".
+///
+/// Implement this to plug in your own rules, for example holidays loaded from a database, and
+/// hand it to [`Parser::business_calendar`](crate::Parser::business_calendar).
+pub trait BusinessCalendar: Debug + Send + Sync {
+    /// Whether `date` falls on the weekend.
+    fn is_weekend(&self, date: NaiveDate) -> bool;
+
+    /// Whether `date` is a holiday. Defaults to `false` for every date.
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        let _ = date;
+        false
+    }
+
+    /// Whether `date` is a business day, which by default means it is neither on the weekend nor
+    /// a holiday.
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+}
+
+/// A business calendar made up of a set of weekend days, a list of holidays and the holidays of a
+/// [`HolidayCalendar`].
+///
+/// The default calendar has Saturday and Sunday as its weekend and no holidays.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use human_date_parser::{BasicCalendar, BusinessCalendar};
+///
+/// let new_year = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
+/// let calendar = BasicCalendar::new([Weekday::Fri, Weekday::Sat]).with_holidays([new_year]);
+///
+/// assert!(!calendar.is_business_day(new_year));
+/// assert!(calendar.is_business_day(NaiveDate::from_ymd_opt(2010, 1, 3).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasicCalendar {
+    weekend: Vec<Weekday>,
+    holidays: BTreeSet<NaiveDate>,
+    holiday_calendar: HolidayCalendar,
+}
+
+impl BasicCalendar {
+    /// Creates a calendar with the given weekend days and no holidays.
+    pub fn new(weekend: impl IntoIterator<Item = Weekday>) -> Self {
+        BasicCalendar {
+            weekend: weekend.into_iter().collect(),
+            holidays: BTreeSet::new(),
+            holiday_calendar: HolidayCalendar::new(),
+        }
+    }
+
+    /// Adds holidays to the calendar.
+    pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Adds the holidays of a [`HolidayCalendar`], like [`HolidayCalendar::builtin`], to the
+    /// calendar.
+    pub fn with_holiday_calendar(mut self, holidays: HolidayCalendar) -> Self {
+        self.holiday_calendar = self.holiday_calendar.merge(holidays);
+        self
+    }
+}
+
+impl Default for BasicCalendar {
+    fn default() -> Self {
+        BasicCalendar::new([Weekday::Sat, Weekday::Sun])
+    }
+}
+
+impl BusinessCalendar for BasicCalendar {
+    fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend.contains(&date.weekday())
+    }
+
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date) || self.holiday_calendar.is_holiday(date)
+    }
+}
+
+/// Moves `dt` by `count` business days, keeping its time of day. Days that are not business
+/// days are skipped over, so one business day after a Friday is the following Monday.
+pub(crate) fn add_business_days(
+    dt: NaiveDateTime,
+    count: u32,
+    direction: Direction,
+    calendar: &dyn BusinessCalendar,
+) -> Result<NaiveDateTime, ProcessingError> {
+    let out_of_range = || match direction {
+        Direction::Forwards => ProcessingError::AddToDate {
+            unit: "business days".to_string(),
+            count,
+            date: dt,
+        },
+        Direction::Backwards => ProcessingError::SubtractFromDate {
+            unit: "business days".to_string(),
+            count,
+            date: dt,
+        },
+    };
+
+    let mut date = dt.date();
+    for _ in 0..count {
+        date = next_business_day(date, direction, calendar)?.ok_or_else(out_of_range)?;
+    }
+
+    Ok(date.and_time(dt.time()))
+}
+
+/// Finds the closest business day after or before `date`, not counting `date` itself. Returns
+/// `None` if the end of the supported range of dates is reached first.
+pub(crate) fn next_business_day(
+    mut date: NaiveDate,
+    direction: Direction,
+    calendar: &dyn BusinessCalendar,
+) -> Result<Option<NaiveDate>, ProcessingError> {
+    for _ in 0..MAX_DAYS_OFF {
+        let next = match direction {
+            Direction::Forwards => date.succ_opt(),
+            Direction::Backwards => date.pred_opt(),
+        };
+        let Some(next) = next else {
+            return Ok(None);
+        };
+        if calendar.is_business_day(next) {
+            return Ok(Some(next));
+        }
+        date = next;
+    }
+
+    Err(ProcessingError::NoBusinessDays { date })
+}
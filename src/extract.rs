@@ -0,0 +1,128 @@
+use std::ops::Range;
+
+use chrono::{Duration, NaiveDateTime};
+
+use crate::ast::{build_ast_from, build_duration_ast_from, build_prefix_ast_from};
+use crate::{duration_from, parse_human_time, ParseResult, Parser};
+
+/// Words that are dates or times on their own but far more often mean something else, like "may"
+/// or "sun". They are only extracted as part of a longer expression, as in "May 3".
+const AMBIGUOUS: &[&str] = &[
+    "may", "march", "jan", "feb", "mar", "apr", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    "mon", "tue", "wed", "thu", "fri", "sat", "sun",
+];
+
+/// A date, time, range or duration found in a piece of text by
+/// [`extract_human_times`](crate::extract_human_times) or [`Parser::extract`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extracted {
+    /// The byte range of the expression in the text.
+    pub span: Range<usize>,
+    /// The expression as it appears in the text.
+    pub text: String,
+    /// What the expression means.
+    pub value: ExtractedValue,
+}
+
+/// What an [`Extracted`] expression means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtractedValue {
+    /// A date, time or range, like "next Friday at 15:00", as [`Parser::parse`] returns it.
+    Time(ParseResult),
+    /// A length of time, like "2 hours and 5 minutes". Months and years are counted from now.
+    Duration(Duration),
+}
+
+/// How many words an expression can span at most.
+const MAX_WORDS: usize = 12;
+
+/// Finds every expression in `text` that `parser` can make sense of. Expressions start at the
+/// beginning of a word and end at the end of one, and the longest one found at a word wins.
+pub(crate) fn extract(parser: &Parser, text: &str, now: NaiveDateTime) -> Vec<Extracted> {
+    let prepared = parser.prepare(text);
+    let prepared_text = prepared.as_str();
+    let options = parser.options();
+
+    let mut found = Vec::new();
+    let mut start = 0;
+    while start < prepared_text.len() {
+        let rest = &prepared_text[start..];
+        let next_char = rest.chars().next().map_or(1, char::len_utf8);
+        let at_word_start = !is_word(prepared_text[..start].chars().next_back())
+            && (is_word(rest.chars().next()) || rest.starts_with('{'));
+        if !at_word_start {
+            start += next_char;
+            continue;
+        }
+
+        // Most words start no expression at all, which one greedy match finds out quickly.
+        if build_prefix_ast_from(rest).is_err() && build_duration_ast_from(rest).is_err() {
+            start += next_char;
+            continue;
+        }
+
+        // Every end of a word up to `MAX_WORDS` words ahead is tried, longest first, so a match
+        // that runs into the middle of a word does not hide a shorter one that fits.
+        let value = candidate_ends(rest).rev().find_map(|len| {
+            let matched = &rest[..len];
+            if AMBIGUOUS.contains(&matched) {
+                return None;
+            }
+            if let Ok(ast) = build_ast_from(matched) {
+                if let Ok(value) = parse_human_time(ast, now, options) {
+                    return Some((ExtractedValue::Time(value), start + len));
+                }
+            }
+            // Durations without a number have to be at least two words, so that "an" and "d" are
+            // not read as a day in "and".
+            let spelled_out =
+                matched.starts_with(|c: char| c.is_ascii_digit()) || matched.contains(' ');
+            let (ast, duration_len) = build_duration_ast_from(matched).ok()?;
+            let value = duration_from(ast, now, options).ok()?;
+            (spelled_out && duration_len == len)
+                .then_some((ExtractedValue::Duration(value), start + len))
+        });
+
+        let Some((value, end)) = value else {
+            start += next_char;
+            continue;
+        };
+
+        let span = prepared.original_range(start..end);
+        found.push(Extracted {
+            text: text[span.clone()].to_string(),
+            span,
+            value,
+        });
+        start = end;
+    }
+
+    found
+}
+
+/// The lengths of the starts of `text` that end at the end of a word, shortest first. Punctuation
+/// right after a word may be part of it, as in "24.12." or "{christmas}", but spaces are not.
+fn candidate_ends(text: &str) -> impl DoubleEndedIterator<Item = usize> {
+    let mut ends = Vec::new();
+    let mut words = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, next)| next);
+        let ends_word = is_word(Some(c)) && !is_word(next);
+        if ends_word {
+            words += 1;
+        }
+        if !c.is_whitespace() && !is_word(next) {
+            ends.push(offset + c.len_utf8());
+        }
+        if words == MAX_WORDS && next.map_or(true, char::is_whitespace) {
+            break;
+        }
+    }
+    ends.into_iter()
+}
+
+/// Whether `c` is part of a word, rather than a space or punctuation between words.
+fn is_word(c: Option<char>) -> bool {
+    c.is_some_and(|c| c.is_alphanumeric() || c == '\'')
+}
@@ -0,0 +1,643 @@
+use chrono::{
+    Datelike, Days, Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime, Timelike,
+    Weekday,
+};
+
+use crate::ast::{Duration as AstDuration, Quantifier, RelativeSpecifier};
+use crate::{
+    apply_duration, days_into_week, find_weekday_by_policy, Direction, ParseOptions, WeekdayPolicy,
+};
+
+/// A unit of time, ordered from the longest to the shortest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl Unit {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Unit::Year => "year",
+            Unit::Month => "month",
+            Unit::Week => "week",
+            Unit::Day => "day",
+            Unit::Hour => "hour",
+            Unit::Minute => "minute",
+            Unit::Second => "second",
+        }
+    }
+
+    fn abbreviation(self) -> &'static str {
+        match self {
+            Unit::Year => "y",
+            Unit::Month => "mo",
+            Unit::Week => "w",
+            Unit::Day => "d",
+            Unit::Hour => "h",
+            Unit::Minute => "m",
+            Unit::Second => "s",
+        }
+    }
+
+    /// The length of the unit in seconds, for the units that have a fixed one.
+    fn seconds(self) -> Option<i64> {
+        match self {
+            Unit::Year | Unit::Month => None,
+            Unit::Week => Some(7 * 24 * 60 * 60),
+            Unit::Day => Some(24 * 60 * 60),
+            Unit::Hour => Some(60 * 60),
+            Unit::Minute => Some(60),
+            Unit::Second => Some(1),
+        }
+    }
+
+    fn quantifier(self, count: u32) -> Quantifier {
+        match self {
+            Unit::Year => Quantifier::Year(count),
+            Unit::Month => Quantifier::Month(count),
+            Unit::Week => Quantifier::Week(count),
+            Unit::Day => Quantifier::Day(count),
+            Unit::Hour => Quantifier::Hour(count),
+            Unit::Minute => Quantifier::Minute(count),
+            Unit::Second => Quantifier::Second(count),
+        }
+    }
+}
+
+/// Options for [`to_human_time`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// The shortest unit of time included in the output. Anything shorter is cut off. Defaults to
+    /// [`Unit::Second`].
+    pub granularity: Unit,
+    /// The maximum number of units in a phrase like "1 year, 2 months and 3 days ago". Defaults to
+    /// `None`, which means no limit.
+    pub max_units: Option<usize>,
+    /// The first day of the week, which decides whether a date is "this week" or "next week".
+    /// Should be the [`ParseOptions::week_start`] the output is parsed with. Defaults to Monday.
+    pub week_start: Weekday,
+    /// The [`ParseOptions::weekday_policy`] the output is parsed with. Phrases like "this Thursday"
+    /// on a Monday mean the same under every policy and are always used. Others, like "next
+    /// Tuesday" on a Friday, are only used under the default [`WeekdayPolicy::NearestUpcoming`].
+    /// Defaults to that policy.
+    pub weekday_policy: WeekdayPolicy,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            granularity: Unit::Second,
+            max_units: None,
+            week_start: Weekday::Mon,
+            weekday_policy: WeekdayPolicy::NearestUpcoming,
+        }
+    }
+}
+
+/// Options for [`humanize_duration`] and [`humanize_duration_between`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationOptions {
+    /// The shortest unit of time included in the output. Defaults to [`Unit::Second`].
+    pub granularity: Unit,
+    /// The maximum number of units in the output, counted from the longest one. Defaults to `None`,
+    /// which means no limit.
+    pub max_units: Option<usize>,
+    /// Whether to write "2h 5m" instead of "2 hours and 5 minutes". Abbreviated units are joined
+    /// with spaces unless `conjunction` is [`Conjunction::Comma`]. Defaults to `false`.
+    pub abbreviate: bool,
+    /// What to do with the part of the duration that is too short to be included. Defaults to
+    /// [`Rounding::Down`].
+    pub rounding: Rounding,
+    /// How the units are joined together. Defaults to [`Conjunction::And`].
+    pub conjunction: Conjunction,
+}
+
+impl Default for DurationOptions {
+    fn default() -> Self {
+        DurationOptions {
+            granularity: Unit::Second,
+            max_units: None,
+            abbreviate: false,
+            rounding: Rounding::Down,
+            conjunction: Conjunction::And,
+        }
+    }
+}
+
+/// How a duration is rounded to the shortest unit included in the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Leave off the rest, so 1 hour and 50 minutes becomes "1 hour".
+    #[default]
+    Down,
+    /// Round to the nearest value, so 1 hour and 50 minutes becomes "2 hours".
+    Nearest,
+    /// Round up if there is any rest, so 1 hour and 10 minutes becomes "2 hours".
+    Up,
+}
+
+/// How the units of a duration are joined together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Conjunction {
+    /// "1 day, 2 hours and 5 minutes", the way durations are parsed. Abbreviated units are joined
+    /// the same as with [`Conjunction::Space`], so they read "1d 2h 5m".
+    #[default]
+    And,
+    /// "1 day, 2 hours, 5 minutes"
+    Comma,
+    /// "1 day 2 hours 5 minutes"
+    Space,
+}
+
+/// Writes out a duration in words, like "2 hours, 32 minutes and 7 seconds".
+///
+/// As the duration is not tied to any date, it is split into weeks and shorter units only. Use
+/// [`humanize_duration_between`] to include months and years. Negative durations are written out
+/// the same as positive ones.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Duration;
+/// use human_date_parser::{humanize_duration, Conjunction, DurationOptions, Rounding, Unit};
+///
+/// let duration = Duration::minutes(125) + Duration::seconds(40);
+/// assert_eq!(
+///     humanize_duration(duration, DurationOptions::default()),
+///     "2 hours, 5 minutes and 40 seconds"
+/// );
+///
+/// let options = DurationOptions {
+///     granularity: Unit::Minute,
+///     abbreviate: true,
+///     rounding: Rounding::Nearest,
+///     ..DurationOptions::default()
+/// };
+/// assert_eq!(humanize_duration(duration, options), "2h 6m");
+/// ```
+pub fn humanize_duration(duration: ChronoDuration, options: DurationOptions) -> String {
+    let seconds = duration.num_seconds().unsigned_abs();
+    let fixed_units = [
+        Unit::Week,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+    ];
+    let granularity = options.granularity.max(Unit::Week);
+    let unit_seconds = |unit: Unit| unit.seconds().unwrap_or(1) as u64;
+
+    // The shortest unit that makes it into the output, given how many units are allowed.
+    let leading = fixed_units
+        .into_iter()
+        .find(|unit| seconds >= unit_seconds(*unit) && *unit <= granularity)
+        .unwrap_or(granularity);
+    let shortest = options
+        .max_units
+        .and_then(|max_units| {
+            fixed_units
+                .into_iter()
+                .filter(|unit| *unit >= leading)
+                .nth(max_units.max(1) - 1)
+        })
+        .map_or(granularity, |unit| unit.min(granularity));
+
+    let step = unit_seconds(shortest);
+    let mut remaining = match options.rounding {
+        Rounding::Down => seconds / step,
+        Rounding::Nearest => (seconds + step / 2) / step,
+        Rounding::Up => (seconds + step - 1) / step,
+    } * step;
+
+    let mut units = Vec::new();
+    for unit in fixed_units.into_iter().filter(|unit| *unit <= shortest) {
+        let count = remaining / unit_seconds(unit);
+        remaining %= unit_seconds(unit);
+        if count > 0 {
+            units.push((unit, count as u32));
+        }
+    }
+    if let Some(max_units) = options.max_units {
+        units.truncate(max_units.max(1));
+    }
+
+    write_units(&units, shortest, &options)
+}
+
+/// Writes out the distance between `from` and `to` in words, like "1 year, 2 months and 3 days".
+///
+/// Unlike [`humanize_duration`], months and years are included, with their lengths taken from the
+/// calendar. Going from `from` by the written out units leads to `to`, cut off or rounded to the
+/// shortest unit included.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use human_date_parser::{humanize_duration_between, DurationOptions, Rounding};
+///
+/// let from = NaiveDate::from_ymd_opt(2010, 1, 31).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2011, 3, 20).unwrap().and_hms_opt(12, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     humanize_duration_between(from, to, DurationOptions::default()),
+///     "1 year, 1 month, 2 weeks, 6 days and 12 hours"
+/// );
+///
+/// let options = DurationOptions {
+///     max_units: Some(2),
+///     rounding: Rounding::Nearest,
+///     ..DurationOptions::default()
+/// };
+/// assert_eq!(humanize_duration_between(from, to, options), "1 year and 2 months");
+/// ```
+pub fn humanize_duration_between(
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+    options: DurationOptions,
+) -> String {
+    let direction = if to >= from {
+        Direction::Forwards
+    } else {
+        Direction::Backwards
+    };
+    let granularity = options.granularity;
+
+    let mut units = calendar_units(from, to, direction, granularity);
+    let shortest = match options.max_units {
+        Some(max_units) if units.len() > max_units.max(1) => {
+            units.truncate(max_units.max(1));
+            units.last().map_or(granularity, |(unit, _)| *unit)
+        }
+        _ => granularity,
+    };
+
+    // Where the units lead to and where one more of the shortest unit would lead to. The rounding
+    // picks one of them.
+    let reached = units.iter().try_fold(from, |dt, (unit, count)| {
+        shift(dt, *unit, *count, direction)
+    });
+    let next = reached.and_then(|reached| shift(reached, shortest, 1, direction));
+    if let (Some(reached), Some(next)) = (reached, next) {
+        let rest = (to - reached).num_seconds().abs();
+        let step = (next - reached).num_seconds().abs();
+        let round_up = match options.rounding {
+            Rounding::Down => false,
+            Rounding::Nearest => rest * 2 >= step,
+            Rounding::Up => rest > 0,
+        };
+        if round_up {
+            units = add_unit(&units, shortest);
+        }
+    }
+
+    write_units(&units, shortest, &options)
+}
+
+/// Adds one of `unit` to `units`, carrying over into longer units where they are full, like 12
+/// months into a year.
+fn add_unit(units: &[(Unit, u32)], unit: Unit) -> Vec<(Unit, u32)> {
+    let mut counts: Vec<(Unit, u32)> = [
+        Unit::Year,
+        Unit::Month,
+        Unit::Week,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+    ]
+    .into_iter()
+    .map(|unit| {
+        let count = units
+            .iter()
+            .find(|(u, _)| *u == unit)
+            .map_or(0, |(_, c)| *c);
+        (unit, count)
+    })
+    .collect();
+
+    let mut carry = Some(unit);
+    for index in (0..counts.len()).rev() {
+        let (current, count) = &mut counts[index];
+        if carry != Some(*current) {
+            continue;
+        }
+        *count += 1;
+        let full = match current {
+            Unit::Month => Some(12),
+            Unit::Day => Some(7),
+            Unit::Hour => Some(24),
+            Unit::Minute | Unit::Second => Some(60),
+            Unit::Year | Unit::Week => None,
+        };
+        carry = None;
+        if full == Some(*count) && index > 0 {
+            *count = 0;
+            carry = Some(counts[index - 1].0);
+        }
+    }
+
+    counts.retain(|(_, count)| *count > 0);
+    counts
+}
+
+/// Writes out units according to `options`, or zero of `shortest` if there are none.
+fn write_units(units: &[(Unit, u32)], shortest: Unit, options: &DurationOptions) -> String {
+    if units.is_empty() {
+        return join_units(&[(shortest, 0)], options);
+    }
+    join_units(units, options)
+}
+
+/// Describes `target` in a human way relative to `now`, like "in 3 days", "yesterday at 18:30",
+/// "next Friday" or "2 hours ago".
+///
+/// The returned phrases are understood by [`from_human_time`](crate::from_human_time). For
+/// granularities of a day or shorter, parsing one with the same `now` gives back `target`, cut off
+/// at that granularity. Longer granularities only name the week, month or year, so parsing "next
+/// month" gives a day in the same month as `target`, but not the same day. Either only holds if
+/// `options.max_units` does not drop any units, and only for the default [`ParseOptions`], apart
+/// from the week start and weekday policy given in `options`. With
+/// [`MonthOverflow::RollOver`](crate::MonthOverflow::RollOver), for example, "in 1 month and 2
+/// days" from January 31st is a different day, and with
+/// [`MonthOverflow::Error`](crate::MonthOverflow::Error) it is an error.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use human_date_parser::{to_human_time, FormatOptions, Unit};
+///
+/// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let target = NaiveDate::from_ymd_opt(2009, 12, 31).unwrap().and_hms_opt(18, 30, 0).unwrap();
+///
+/// assert_eq!(to_human_time(target, now, FormatOptions::default()), "yesterday at 18:30");
+///
+/// let options = FormatOptions {
+///     granularity: Unit::Day,
+///     ..FormatOptions::default()
+/// };
+/// assert_eq!(to_human_time(target, now, options), "yesterday");
+/// ```
+pub fn to_human_time(target: NaiveDateTime, now: NaiveDateTime, options: FormatOptions) -> String {
+    let granularity = options.granularity;
+    if granularity < Unit::Day {
+        return format_coarse(target, now, granularity, options.week_start);
+    }
+
+    let target = truncate(target, granularity);
+    let now = truncate(now, granularity);
+    if target == now {
+        return match granularity {
+            Unit::Day => "today".to_string(),
+            _ => "now".to_string(),
+        };
+    }
+
+    let days = (target.date() - now.date()).num_days();
+    let close = (target - now).abs() < ChronoDuration::hours(6);
+    if granularity > Unit::Day && close {
+        return format_relative(target, now, granularity, options.max_units);
+    }
+
+    let date = match days {
+        -1 => Some("yesterday".to_string()),
+        0 => Some("today".to_string()),
+        1 => Some("tomorrow".to_string()),
+        2 => Some("overmorrow".to_string()),
+        3..=6 | -6..=-2 => weekday_phrase(target.date(), now.date(), &options),
+        _ => None,
+    };
+
+    match (date, granularity) {
+        (Some(date), Unit::Day) => date,
+        (Some(date), _) => format!("{date} at {}", format_time(target.time())),
+        (None, _) => format_relative(target, now, granularity, options.max_units),
+    }
+}
+
+/// Names `target` by its weekday, like "next Tuesday" or "this Sunday", if the parser the output is
+/// for reads the phrase as `target`. See [`FormatOptions::weekday_policy`].
+fn weekday_phrase(target: NaiveDate, now: NaiveDate, options: &FormatOptions) -> Option<String> {
+    let policies = [
+        WeekdayPolicy::NearestUpcoming,
+        WeekdayPolicy::CalendarWeek,
+        WeekdayPolicy::SkipOne,
+    ];
+    let specifiers = [
+        (RelativeSpecifier::This, "this"),
+        (RelativeSpecifier::Next, "next"),
+        (RelativeSpecifier::Last, "last"),
+    ];
+    let means_target = |relative, policy| {
+        find_weekday_by_policy(relative, target.weekday(), now, policy, options.week_start).ok()
+            == Some(target)
+    };
+
+    let unambiguous = specifiers.iter().find(|(relative, _)| {
+        policies
+            .into_iter()
+            .all(|policy| means_target(*relative, policy))
+    });
+    // The default policy reads "this Tuesday" the same as "next Tuesday", which is used instead.
+    let default = specifiers[1..].iter().find(|(relative, _)| {
+        options.weekday_policy == WeekdayPolicy::default()
+            && means_target(*relative, WeekdayPolicy::default())
+    });
+
+    unambiguous
+        .or(default)
+        .map(|(_, word)| format!("{word} {}", weekday_name(target.weekday())))
+}
+
+/// Formats `target` for granularities longer than a day, like "next month" or "in 3 years".
+fn format_coarse(
+    target: NaiveDateTime,
+    now: NaiveDateTime,
+    granularity: Unit,
+    first_day: Weekday,
+) -> String {
+    let (target, now) = (target.date(), now.date());
+    let difference = match granularity {
+        Unit::Year => (target.year() - now.year()) as i64,
+        Unit::Month => {
+            (target.year() - now.year()) as i64 * 12 + target.month0() as i64 - now.month0() as i64
+        }
+        _ => (week_start(target, first_day) - week_start(now, first_day)).num_days() / 7,
+    };
+
+    let name = granularity.name();
+    match difference {
+        0 => format!("this {name}"),
+        1 => format!("next {name}"),
+        -1 => format!("last {name}"),
+        count => join_relative(&[(granularity, count.unsigned_abs() as u32)], count > 0),
+    }
+}
+
+/// Formats the distance between `now` and `target` as a duration, like "in 2 hours" or
+/// "1 month and 3 days ago".
+fn format_relative(
+    target: NaiveDateTime,
+    now: NaiveDateTime,
+    granularity: Unit,
+    max_units: Option<usize>,
+) -> String {
+    let direction = if target >= now {
+        Direction::Forwards
+    } else {
+        Direction::Backwards
+    };
+    let mut units = calendar_units(now, target, direction, granularity);
+    if let Some(max_units) = max_units {
+        units.truncate(max_units.max(1));
+    }
+
+    join_relative(&units, direction == Direction::Forwards)
+}
+
+fn join_relative(units: &[(Unit, u32)], forwards: bool) -> String {
+    let duration = join_units(units, &DurationOptions::default());
+    if forwards {
+        format!("in {duration}")
+    } else {
+        format!("{duration} ago")
+    }
+}
+
+/// Joins units like `[(Year, 1), (Month, 2), (Day, 3)]` into "1 year, 2 months and 3 days", or
+/// the style given by `options`.
+fn join_units(units: &[(Unit, u32)], options: &DurationOptions) -> String {
+    let words: Vec<String> = units
+        .iter()
+        .map(|(unit, count)| match (options.abbreviate, count) {
+            (true, count) => format!("{count}{}", unit.abbreviation()),
+            (false, 1) => format!("1 {}", unit.name()),
+            (false, count) => format!("{count} {}s", unit.name()),
+        })
+        .collect();
+
+    match (options.conjunction, words.split_last()) {
+        (_, None) => String::new(),
+        (_, Some((last, []))) => last.clone(),
+        (Conjunction::And, Some(_)) if options.abbreviate => words.join(" "),
+        (Conjunction::And, Some((last, rest))) => format!("{} and {last}", rest.join(", ")),
+        (Conjunction::Comma, _) => words.join(", "),
+        (Conjunction::Space, _) => words.join(" "),
+    }
+}
+
+/// Splits the distance between `from` and `to` into calendar units, from years down to
+/// `granularity`. Applying the units to `from` in order, the way the parser does, leads to `to`
+/// cut off at `granularity`. Units that would be zero are left out.
+fn calendar_units(
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+    direction: Direction,
+    granularity: Unit,
+) -> Vec<(Unit, u32)> {
+    let to = truncate(to, granularity);
+    let passes = |dt: NaiveDateTime| match direction {
+        Direction::Forwards => dt > to,
+        Direction::Backwards => dt < to,
+    };
+
+    let mut current = from;
+    let mut units = Vec::new();
+    for unit in [Unit::Year, Unit::Month] {
+        if unit > granularity {
+            break;
+        }
+
+        let months =
+            (to.year() - current.year()) * 12 + to.month0() as i32 - current.month0() as i32;
+        let estimate = match unit {
+            Unit::Year => months / 12,
+            _ => months,
+        };
+
+        // The estimate can be one too high, as the day of the month is not taken into account.
+        let mut count = estimate.unsigned_abs() + 1;
+        let shifted = loop {
+            if count == 0 {
+                break None;
+            }
+            match shift(current, unit, count, direction) {
+                Some(shifted) if !passes(shifted) => break Some(shifted),
+                _ => count -= 1,
+            }
+        };
+        if let Some(shifted) = shifted {
+            current = shifted;
+            units.push((unit, count));
+        }
+    }
+
+    let mut remaining = (to - current).num_seconds().abs();
+    for unit in [
+        Unit::Week,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+    ] {
+        let Some(seconds) = unit.seconds().filter(|_| unit <= granularity) else {
+            break;
+        };
+        let count = remaining / seconds;
+        remaining %= seconds;
+        if count > 0 {
+            units.push((unit, count as u32));
+        }
+    }
+
+    units
+}
+
+fn shift(dt: NaiveDateTime, unit: Unit, count: u32, direction: Direction) -> Option<NaiveDateTime> {
+    let duration = AstDuration(vec![unit.quantifier(count)]);
+    apply_duration(duration, dt, direction, &ParseOptions::default()).ok()
+}
+
+/// Cuts off everything shorter than `granularity`, for the granularities up to a day.
+fn truncate(dt: NaiveDateTime, granularity: Unit) -> NaiveDateTime {
+    let time = match granularity {
+        Unit::Year | Unit::Month | Unit::Week | Unit::Day => NaiveTime::MIN,
+        Unit::Hour => NaiveTime::from_hms_opt(dt.hour(), 0, 0).unwrap_or(NaiveTime::MIN),
+        Unit::Minute => {
+            NaiveTime::from_hms_opt(dt.hour(), dt.minute(), 0).unwrap_or(NaiveTime::MIN)
+        }
+        Unit::Second => dt.time().with_nanosecond(0).unwrap_or(dt.time()),
+    };
+    dt.date().and_time(time)
+}
+
+fn format_time(time: NaiveTime) -> String {
+    if time.second() == 0 {
+        time.format("%H:%M").to_string()
+    } else {
+        time.format("%H:%M:%S").to_string()
+    }
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+fn week_start(date: NaiveDate, first_day: Weekday) -> NaiveDate {
+    date - Days::new(days_into_week(date, first_day))
+}
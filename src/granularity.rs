@@ -0,0 +1,120 @@
+use crate::ast::{self, Ago, Date, Duration, HumanTime, Quantifier, RangeBound, Time, TimeUnit};
+
+/// How precise a parsed expression is, from the coarsest to the finest.
+///
+/// "Next month" for example resolves to a single date, but the user only meant the month. A search
+/// filter can use this to widen the date to the whole month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Granularity {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    /// Finer than a second, which only "now" is.
+    Subsecond,
+}
+
+impl From<TimeUnit> for Granularity {
+    fn from(time_unit: TimeUnit) -> Self {
+        match time_unit {
+            TimeUnit::Year => Granularity::Year,
+            TimeUnit::Quarter => Granularity::Quarter,
+            TimeUnit::Month => Granularity::Month,
+            TimeUnit::Week => Granularity::Week,
+            TimeUnit::BusinessDay | TimeUnit::Day => Granularity::Day,
+            TimeUnit::Hour => Granularity::Hour,
+            TimeUnit::Minute => Granularity::Minute,
+            TimeUnit::Second => Granularity::Second,
+        }
+    }
+}
+
+/// Finds the granularity of a parsed expression. Expressions made up of several parts, like
+/// ranges or "3 days ago 17:00", are as precise as their finest part.
+pub(crate) fn granularity_of(human_time: &HumanTime) -> Granularity {
+    match human_time {
+        HumanTime::DateTime(date_time) => time_granularity(&date_time.time),
+        HumanTime::Date(date) => date_granularity(date),
+        HumanTime::Time(time) => time_granularity(time),
+        HumanTime::In(ast::In(duration)) | HumanTime::Ago(Ago::AgoFromNow(duration)) => {
+            duration_granularity(duration).unwrap_or(Granularity::Subsecond)
+        }
+        HumanTime::Ago(Ago::AgoFromTime(duration, human_time)) => {
+            let granularity = granularity_of(human_time);
+            duration_granularity(duration).map_or(granularity, |g| g.max(granularity))
+        }
+        HumanTime::Now => Granularity::Subsecond,
+        HumanTime::Range(range) => {
+            bound_granularity(&range.start).max(bound_granularity(&range.end))
+        }
+    }
+}
+
+fn date_granularity(date: &Date) -> Granularity {
+    match date {
+        Date::Quarter(_) | Date::QuarterYear(_, _) => Granularity::Quarter,
+        Date::RelativeTimeUnit(_, time_unit) => (*time_unit).into(),
+        Date::Year(_) => Granularity::Year,
+        Date::MonthYear(_, _) | Date::Month(_) | Date::RelativeMonth(_, _) => Granularity::Month,
+        Date::Today
+        | Date::Tomorrow
+        | Date::Overmorrow
+        | Date::Yesterday
+        | Date::IsoDate(_)
+        | Date::DayMonthYear(_, _, _)
+        | Date::DayMonth(_, _)
+        | Date::DottedDayMonth(_, _)
+        | Date::RelativeWeekWeekday(_, _)
+        | Date::RelativeWeekday(_, _)
+        | Date::UpcomingWeekday(_)
+        | Date::StartOfWeek(_)
+        | Date::EndOfWeek(_)
+        | Date::Weekend(_)
+        | Date::Workweek(_)
+        | Date::Named(_, _)
+        | Date::RelativeNamed(_, _) => Granularity::Day,
+        // The result is a date, so it is never finer than a day, even with hours in the offset.
+        Date::Offset(duration, _, date) => {
+            let granularity = date_granularity(date);
+            duration_granularity(duration)
+                .map_or(granularity, |g| g.max(granularity))
+                .min(Granularity::Day)
+        }
+    }
+}
+
+fn time_granularity(time: &Time) -> Granularity {
+    match time {
+        Time::HourMinute(_, _) => Granularity::Minute,
+        Time::HourMinuteSecond(_, _, _) => Granularity::Second,
+    }
+}
+
+/// The granularity of the shortest unit in a duration like "2 hours and 5 minutes".
+fn duration_granularity(duration: &Duration) -> Option<Granularity> {
+    duration
+        .0
+        .iter()
+        .map(|quantifier| match quantifier {
+            Quantifier::Year(_) => Granularity::Year,
+            Quantifier::Quarter(_) => Granularity::Quarter,
+            Quantifier::Month(_) => Granularity::Month,
+            Quantifier::Week(_) => Granularity::Week,
+            Quantifier::BusinessDay(_) | Quantifier::Day(_) => Granularity::Day,
+            Quantifier::Hour(_) => Granularity::Hour,
+            Quantifier::Minute(_) => Granularity::Minute,
+            Quantifier::Second(_) => Granularity::Second,
+        })
+        .max()
+}
+
+fn bound_granularity(bound: &RangeBound) -> Granularity {
+    match bound {
+        RangeBound::HumanTime(human_time) => granularity_of(human_time),
+        RangeBound::Hour(_) => Granularity::Hour,
+    }
+}
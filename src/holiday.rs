@@ -0,0 +1,338 @@
+use std::sync::OnceLock;
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+/// How the date of a holiday is found for a given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HolidayRule {
+    /// The same day every year, like Christmas on December 25th.
+    Fixed { month: u32, day: u32 },
+    /// The nth weekday of a month, like Thanksgiving on the fourth Thursday of November. An
+    /// `ordinal` of `-1` means the last such weekday of the month.
+    NthWeekday {
+        month: u32,
+        ordinal: i32,
+        weekday: Weekday,
+    },
+    /// A number of days before or after Easter Sunday, like Good Friday two days before it.
+    Easter { offset: i32 },
+}
+
+impl HolidayRule {
+    /// Finds the date the rule falls on in `year`, or `None` if there is no such day.
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            HolidayRule::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayRule::NthWeekday {
+                month,
+                ordinal,
+                weekday,
+            } if ordinal < 0 => {
+                let next_month = if month == 12 {
+                    NaiveDate::from_ymd_opt(year + 1, 1, 1)
+                } else {
+                    NaiveDate::from_ymd_opt(year, month + 1, 1)
+                }?;
+                let last = next_month.pred_opt()?;
+                let back = (7 + last.weekday().num_days_from_monday()
+                    - weekday.num_days_from_monday())
+                    % 7;
+                let weeks = (-ordinal - 1) as u64;
+                let date = last.checked_sub_days(Days::new(back as u64 + weeks * 7))?;
+                (date.month() == month).then_some(date)
+            }
+            HolidayRule::NthWeekday {
+                month,
+                ordinal,
+                weekday,
+            } => NaiveDate::from_weekday_of_month_opt(
+                year,
+                month,
+                weekday,
+                u8::try_from(ordinal).ok()?,
+            ),
+            HolidayRule::Easter { offset } => {
+                let easter = easter_sunday(year)?;
+                if offset < 0 {
+                    easter.checked_sub_days(Days::new(offset.unsigned_abs() as u64))
+                } else {
+                    easter.checked_add_days(Days::new(offset as u64))
+                }
+            }
+        }
+    }
+}
+
+/// Finds Easter Sunday of the Gregorian calendar with the anonymous Gregorian computus.
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c.div_euclid(4);
+    let k = c.rem_euclid(4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l).div_euclid(451);
+    let month = (h + l - 7 * m + 114).div_euclid(31);
+    let day = (h + l - 7 * m + 114).rem_euclid(31) + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// A named holiday, like "Thanksgiving". It can be referred to by its name or any of its aliases,
+/// regardless of case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holiday {
+    name: String,
+    aliases: Vec<String>,
+    rule: HolidayRule,
+}
+
+impl Holiday {
+    /// Creates a holiday with the given name and rule.
+    pub fn new(name: impl Into<String>, rule: HolidayRule) -> Self {
+        Holiday {
+            name: name.into(),
+            aliases: Vec::new(),
+            rule,
+        }
+    }
+
+    /// Adds another name the holiday can be referred to by, like "Xmas" for Christmas.
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// The name of the holiday.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The rule the date of the holiday follows.
+    pub fn rule(&self) -> HolidayRule {
+        self.rule
+    }
+
+    /// The name and all aliases of the holiday.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+
+    /// Finds the date of the holiday in `year`.
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        self.rule.date_in(year)
+    }
+
+    fn is_called(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        self.names().any(|own| own.to_lowercase() == name)
+    }
+}
+
+/// A collection of holidays that can be referred to by name, as in "the day after Thanksgiving"
+/// or "Easter 2026", and used as the holidays of a [`BasicCalendar`](crate::BasicCalendar).
+///
+/// [`HolidayCalendar::builtin`] is what a [`Parser`](crate::Parser) uses by default. It is made up
+/// of [`HolidayCalendar::common`] and the regional sets enabled through the `holidays-us`,
+/// `holidays-gb` and `holidays-de` features, of which `holidays-us` is enabled by default.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use human_date_parser::{Holiday, HolidayCalendar, HolidayRule};
+///
+/// let holidays = HolidayCalendar::common()
+///     .with_holiday(Holiday::new("Founders Day", HolidayRule::Fixed { month: 3, day: 14 }));
+///
+/// let easter = holidays.find("easter").unwrap();
+/// assert_eq!(easter.date_in(2026), NaiveDate::from_ymd_opt(2026, 4, 5));
+/// assert!(holidays.is_holiday(NaiveDate::from_ymd_opt(2026, 3, 14).unwrap()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    holidays: Vec<Holiday>,
+}
+
+impl HolidayCalendar {
+    /// Creates a calendar without any holidays.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The common holidays and all regional sets enabled through cargo features.
+    pub fn builtin() -> Self {
+        // Every parser with default options starts out with these, so they are only built once.
+        static BUILTIN: OnceLock<HolidayCalendar> = OnceLock::new();
+        BUILTIN.get_or_init(Self::build_builtin).clone()
+    }
+
+    fn build_builtin() -> Self {
+        #[allow(unused_mut)]
+        let mut calendar = Self::common();
+        #[cfg(feature = "holidays-us")]
+        {
+            calendar = calendar.merge(Self::us());
+        }
+        #[cfg(feature = "holidays-gb")]
+        {
+            calendar = calendar.merge(Self::gb());
+        }
+        #[cfg(feature = "holidays-de")]
+        {
+            calendar = calendar.merge(Self::de());
+        }
+        calendar
+    }
+
+    /// Holidays observed in many countries: New Year's Day and Eve, Christmas Eve, Christmas and
+    /// the holidays around Easter.
+    pub fn common() -> Self {
+        use HolidayRule::{Easter, Fixed};
+
+        Self::new()
+            .with_holiday(
+                Holiday::new("New Year's Day", Fixed { month: 1, day: 1 })
+                    .alias("New Years Day")
+                    .alias("New Year's")
+                    .alias("New Years"),
+            )
+            .with_holiday(
+                Holiday::new("New Year's Eve", Fixed { month: 12, day: 31 })
+                    .alias("New Years Eve")
+                    .alias("Silvester"),
+            )
+            .with_holiday(
+                Holiday::new("Christmas Eve", Fixed { month: 12, day: 24 }).alias("Xmas Eve"),
+            )
+            .with_holiday(
+                Holiday::new("Christmas", Fixed { month: 12, day: 25 })
+                    .alias("Christmas Day")
+                    .alias("Xmas"),
+            )
+            .with_holiday(Holiday::new("Good Friday", Easter { offset: -2 }))
+            .with_holiday(
+                Holiday::new("Easter", Easter { offset: 0 })
+                    .alias("Easter Sunday")
+                    .alias("Easter Day"),
+            )
+            .with_holiday(Holiday::new("Easter Monday", Easter { offset: 1 }))
+            .with_holiday(Holiday::new("Ascension Day", Easter { offset: 39 }).alias("Ascension"))
+            .with_holiday(
+                Holiday::new("Pentecost", Easter { offset: 49 })
+                    .alias("Whit Sunday")
+                    .alias("Whitsun"),
+            )
+            .with_holiday(Holiday::new("Whit Monday", Easter { offset: 50 }))
+    }
+
+    /// Federal holidays of the United States.
+    #[cfg(feature = "holidays-us")]
+    pub fn us() -> Self {
+        use chrono::Weekday::{Mon, Thu};
+        use HolidayRule::{Fixed, NthWeekday};
+
+        let nth = |month, ordinal, weekday| NthWeekday {
+            month,
+            ordinal,
+            weekday,
+        };
+
+        Self::new()
+            .with_holiday(
+                Holiday::new("Martin Luther King Jr. Day", nth(1, 3, Mon))
+                    .alias("Martin Luther King Day")
+                    .alias("MLK Day"),
+            )
+            .with_holiday(
+                Holiday::new("Presidents' Day", nth(2, 3, Mon))
+                    .alias("Presidents Day")
+                    .alias("Washington's Birthday"),
+            )
+            .with_holiday(Holiday::new("Memorial Day", nth(5, -1, Mon)))
+            .with_holiday(Holiday::new("Juneteenth", Fixed { month: 6, day: 19 }))
+            .with_holiday(
+                Holiday::new("Independence Day", Fixed { month: 7, day: 4 })
+                    .alias("Fourth of July"),
+            )
+            .with_holiday(Holiday::new("Labor Day", nth(9, 1, Mon)))
+            .with_holiday(Holiday::new("Columbus Day", nth(10, 2, Mon)))
+            .with_holiday(Holiday::new("Veterans Day", Fixed { month: 11, day: 11 }))
+            .with_holiday(Holiday::new("Thanksgiving", nth(11, 4, Thu)).alias("Thanksgiving Day"))
+    }
+
+    /// Bank holidays of England and Wales that are not part of [`HolidayCalendar::common`].
+    #[cfg(feature = "holidays-gb")]
+    pub fn gb() -> Self {
+        use chrono::Weekday::Mon;
+        use HolidayRule::{Fixed, NthWeekday};
+
+        let nth = |month, ordinal, weekday| NthWeekday {
+            month,
+            ordinal,
+            weekday,
+        };
+
+        Self::new()
+            .with_holiday(Holiday::new("Early May Bank Holiday", nth(5, 1, Mon)))
+            .with_holiday(Holiday::new("Spring Bank Holiday", nth(5, -1, Mon)))
+            .with_holiday(Holiday::new("Summer Bank Holiday", nth(8, -1, Mon)))
+            .with_holiday(Holiday::new("Boxing Day", Fixed { month: 12, day: 26 }))
+    }
+
+    /// Nationwide public holidays of Germany that are not part of [`HolidayCalendar::common`].
+    #[cfg(feature = "holidays-de")]
+    pub fn de() -> Self {
+        use HolidayRule::Fixed;
+
+        Self::new()
+            .with_holiday(
+                Holiday::new("Tag der Arbeit", Fixed { month: 5, day: 1 })
+                    .alias("Labour Day")
+                    .alias("May Day"),
+            )
+            .with_holiday(
+                Holiday::new("Tag der Deutschen Einheit", Fixed { month: 10, day: 3 })
+                    .alias("German Unity Day"),
+            )
+            .with_holiday(
+                Holiday::new("Zweiter Weihnachtstag", Fixed { month: 12, day: 26 })
+                    .alias("St. Stephen's Day"),
+            )
+    }
+
+    /// Adds a holiday, replacing any holiday that shares one of its names.
+    pub fn with_holiday(mut self, holiday: Holiday) -> Self {
+        self.holidays
+            .retain(|existing| !holiday.names().any(|name| existing.is_called(name)));
+        self.holidays.push(holiday);
+        self
+    }
+
+    /// Adds all holidays of `other`, replacing the ones that share a name.
+    pub fn merge(self, other: HolidayCalendar) -> Self {
+        other.holidays.into_iter().fold(self, Self::with_holiday)
+    }
+
+    /// Finds a holiday by its name or one of its aliases, regardless of case.
+    pub fn find(&self, name: &str) -> Option<&Holiday> {
+        self.holidays.iter().find(|holiday| holiday.is_called(name))
+    }
+
+    /// All holidays in the calendar.
+    pub fn holidays(&self) -> &[Holiday] {
+        &self.holidays
+    }
+
+    /// Whether any of the holidays falls on `date`.
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays
+            .iter()
+            .any(|holiday| holiday.date_in(date.year()) == Some(date))
+    }
+}
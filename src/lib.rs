@@ -1,23 +1,53 @@
 use std::fmt::Display;
 
 use ast::{
-    build_ast_from, Ago, Date, DateTime, Duration as AstDuration, In, IsoDate, Quantifier,
-    RelativeSpecifier, Time, TimeUnit,
+    Ago, Date, DateTime, Duration as AstDuration, In, IsoDate, OffsetDirection, Quantifier, Range,
+    RangeBound, RelativeSpecifier, Time, TimeUnit,
 };
+use business::add_business_days;
 use chrono::{
-    Datelike, Days, Duration as ChronoDuration, Month, Months, NaiveDate, NaiveDateTime,
-    NaiveTime, Weekday,
+    Datelike, Days, Duration as ChronoDuration, Month, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    Weekday,
 };
 use thiserror::Error;
 
+pub use anchor::Anchor;
+pub use business::{BasicCalendar, BusinessCalendar};
+pub use extract::{Extracted, ExtractedValue};
+pub use format::{
+    humanize_duration, humanize_duration_between, to_human_time, Conjunction, DurationOptions,
+    FormatOptions, Rounding, Unit,
+};
+pub use granularity::Granularity;
+pub use holiday::{Holiday, HolidayCalendar, HolidayRule};
+pub use locale::Locale;
+pub use normalize::Normalized;
+pub use parser::{MonthOverflow, ParseOptions, Parser, Preference, WeekdayPolicy};
+pub use recurrence::{
+    CronError, Frequency, Occurrences, Recurrence, RecurrenceLimit, RecurrencePattern, RruleError,
+};
+pub use syntax::SyntaxError;
+
+mod anchor;
 mod ast;
+mod business;
+mod extract;
+mod format;
+mod granularity;
+mod holiday;
+mod locale;
+mod normalize;
+mod parser;
+mod recurrence;
+mod suggest;
+mod syntax;
 #[cfg(test)]
 mod tests;
 
 #[derive(Debug, Error)]
 pub enum ParseError {
-    #[error("Could not match input to any known format")]
-    InvalidFormat,
+    #[error("{0}")]
+    InvalidFormat(SyntaxError),
     #[error("One or more errors occured when processing input")]
     ProccessingErrors(Vec<ProcessingError>),
     #[error(
@@ -52,19 +82,51 @@ pub enum ProcessingError {
     InvalidDate { year: i32, month: u32, day: u32 },
     #[error("Failed to parse inner human time: {0}")]
     InnerHumanTimeParse(Box<ParseError>),
+    #[error("{hour} is not a valid hour")]
+    InvalidHour { hour: u32 },
+    #[error("The {unit} containing {date} lies outside of the supported range of dates")]
+    PeriodOutOfRange { unit: String, date: NaiveDate },
+    #[error("{day} is not a valid day of the month")]
+    InvalidDayOfMonth { day: u32 },
+    #[error("Something can not repeat every 0 units of time")]
+    ZeroInterval,
+    #[error("Something can not repeat every {interval} {unit}, the interval is too large")]
+    IntervalTooLarge { interval: u32, unit: String },
+    #[error("A time of day can not be given for something that repeats {unit}")]
+    RecurrenceTimeOfDay { unit: String },
+    #[error("The weekend has to be made up of at least one and at most six days")]
+    InvalidWeekend,
+    #[error("The business calendar has no business day within a year of {date}")]
+    NoBusinessDays { date: NaiveDate },
+    #[error("There is no date called {name}")]
+    UnknownName { name: String },
+    #[error("{name} can not be combined with a year or \"this\", \"next\" or \"last\"")]
+    AnchorWithSpecifier { name: String },
+    #[error("{name} does not fall on any day in {year}")]
+    NoDateInYear { name: String, year: i32 },
+    #[error("\"this\", \"next\" or \"last\" {unit} does not name a date")]
+    RelativeTimeUnit { unit: String },
+    #[error("The range ends at {end}, which is before its start at {start}")]
+    RangeEndBeforeStart {
+        start: ParseResult,
+        end: ParseResult,
+    },
 }
 
 #[derive(Debug, Error)]
 pub enum InternalError {
     #[error("Failed to build AST. This is a bug.")]
     FailedToBuildAst,
+    #[error("Encountered a range where a single point in time was expected. This is a bug.")]
+    UnexpectedRange,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParseResult {
     DateTime(NaiveDateTime),
     Date(NaiveDate),
     Time(NaiveTime),
+    Range(ParseRange),
 }
 
 impl Display for ParseResult {
@@ -73,15 +135,164 @@ impl Display for ParseResult {
             ParseResult::DateTime(datetime) => write!(f, "{}", datetime),
             ParseResult::Date(date) => write!(f, "{}", date),
             ParseResult::Time(time) => write!(f, "{}", time),
+            ParseResult::Range(range) => write!(f, "{}", range),
+        }
+    }
+}
+
+/// The time of day used by [`ParseResult::to_datetime`] for results that are only a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultTime {
+    /// Midnight at the start of the day.
+    StartOfDay,
+    /// The last second of the day, 23:59:59.
+    EndOfDay,
+    /// The time of day of `now`.
+    Now,
+    /// A fixed time of day, like 9:00 for the start of business.
+    Fixed(NaiveTime),
+}
+
+impl DefaultTime {
+    fn time(self, now: &NaiveDateTime) -> NaiveTime {
+        match self {
+            DefaultTime::StartOfDay => NaiveTime::MIN,
+            DefaultTime::EndOfDay => end_of_day(),
+            DefaultTime::Now => now.time(),
+            DefaultTime::Fixed(time) => time,
+        }
+    }
+}
+
+impl ParseResult {
+    /// Turns the result into a single point in time. Dates get the time of day given by
+    /// `default_time` and times fall on the date of `now`. Returns `None` for ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use human_date_parser::{from_human_time, DefaultTime};
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let result = from_human_time("Tomorrow", now).unwrap();
+    ///
+    /// assert_eq!(
+    ///     result.to_datetime(now, DefaultTime::StartOfDay),
+    ///     NaiveDate::from_ymd_opt(2010, 1, 2).unwrap().and_hms_opt(0, 0, 0)
+    /// );
+    /// ```
+    pub fn to_datetime(
+        &self,
+        now: NaiveDateTime,
+        default_time: DefaultTime,
+    ) -> Option<NaiveDateTime> {
+        match *self {
+            ParseResult::DateTime(datetime) => Some(datetime),
+            ParseResult::Date(date) => Some(date.and_time(default_time.time(&now))),
+            ParseResult::Time(time) => Some(now.date().and_time(time)),
+            ParseResult::Range(_) => None,
+        }
+    }
+
+    /// Returns the date of the result, if it has one.
+    pub fn date(&self) -> Option<NaiveDate> {
+        match *self {
+            ParseResult::DateTime(datetime) => Some(datetime.date()),
+            ParseResult::Date(date) => Some(date),
+            ParseResult::Time(_) | ParseResult::Range(_) => None,
+        }
+    }
+
+    /// Returns the time of day of the result, if it has one.
+    pub fn time(&self) -> Option<NaiveTime> {
+        match *self {
+            ParseResult::DateTime(datetime) => Some(datetime.time()),
+            ParseResult::Time(time) => Some(time),
+            ParseResult::Date(_) | ParseResult::Range(_) => None,
+        }
+    }
+}
+
+/// Returned when converting a [`ParseResult`] into a type that does not match its variant, like
+/// a `ParseResult::Date` into a `NaiveDateTime`. The result is handed back unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("Can not convert {result} into a {expected}")]
+pub struct ConversionError {
+    pub expected: &'static str,
+    pub result: ParseResult,
+}
+
+macro_rules! impl_try_from_parse_result {
+    ( $( $variant:ident => $target:ty ),* ) => {
+        $(
+            impl TryFrom<ParseResult> for $target {
+                type Error = ConversionError;
+
+                fn try_from(result: ParseResult) -> Result<Self, Self::Error> {
+                    match result {
+                        ParseResult::$variant(value) => Ok(value),
+                        result => Err(ConversionError {
+                            expected: stringify!($target),
+                            result,
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_parse_result!(
+    DateTime => NaiveDateTime,
+    Date => NaiveDate,
+    Time => NaiveTime,
+    Range => ParseRange
+);
+
+/// An interval between two points in time, like "from Monday to Friday" or "9:00-17:00".
+///
+/// Both bounds are always of the same kind. If the input mixes kinds, for example
+/// "today 9:00 until 17:00", the bounds are widened to a `DateTime` range. Date ranges include
+/// their end date, so "March 3-7" covers five days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseRange {
+    DateTime(NaiveDateTime, NaiveDateTime),
+    Date(NaiveDate, NaiveDate),
+    Time(NaiveTime, NaiveTime),
+}
+
+impl ParseRange {
+    /// Returns the beginning of the range.
+    pub fn start(&self) -> ParseResult {
+        match *self {
+            ParseRange::DateTime(start, _) => ParseResult::DateTime(start),
+            ParseRange::Date(start, _) => ParseResult::Date(start),
+            ParseRange::Time(start, _) => ParseResult::Time(start),
+        }
+    }
+
+    /// Returns the end of the range.
+    pub fn end(&self) -> ParseResult {
+        match *self {
+            ParseRange::DateTime(_, end) => ParseResult::DateTime(end),
+            ParseRange::Date(_, end) => ParseResult::Date(end),
+            ParseRange::Time(_, end) => ParseResult::Time(end),
         }
     }
 }
 
+impl Display for ParseRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.start(), self.end())
+    }
+}
+
 /// Parses a human-readable date or time string and converts it into a structured date/time format.
 ///
 /// This function takes a string representing a human-readable date/time expression (e.g.,
 /// "Last Friday at 19:45") and attempts to parse it into one of three possible formats:
-/// `NaiveDateTime`, `NaiveDate`, or `NaiveTime`. The function requires a reference date (`now`)
+/// `NaiveDateTime`, `NaiveDate`, or `NaiveTime`, or a range between two of them. The function requires a reference date (`now`)
 /// to properly resolve relative time expressions.
 ///
 /// # Parameters
@@ -95,6 +306,8 @@ impl Display for ParseResult {
 /// - `Ok(ParseResult::DateTime(dt))` if the input string represents a full date and time.
 /// - `Ok(ParseResult::Date(d))` if the input string represents only a date.
 /// - `Ok(ParseResult::Time(t))` if the input string represents only a time.
+/// - `Ok(ParseResult::Range(r))` if the input string represents a range like "from Monday to
+///   Friday". Ranges whose end lies before their start are rejected.
 /// - `Err(ParseError)` if parsing fails due to an unrecognized or invalid format.
 ///
 /// # Errors
@@ -102,6 +315,9 @@ impl Display for ParseResult {
 /// This function returns an error if the input string contains values that cannot be parsed
 /// into a valid date or time.
 ///
+/// This uses a [`Parser`] with the default [`ParseOptions`]. Build a `Parser` yourself to change
+/// how the input is interpreted.
+///
 /// # Examples
 ///
 /// ```
@@ -130,35 +346,178 @@ impl Display for ParseResult {
 /// }
 /// ```
 pub fn from_human_time(str: &str, now: NaiveDateTime) -> Result<ParseResult, ParseError> {
-    let lowercase = str.to_lowercase();
-    let parsed = build_ast_from(&lowercase)?;
+    Parser::new().parse(str, now)
+}
 
-    parse_human_time(parsed, now)
+/// Parses a human-readable date or time string into the whole period of time it refers to.
+///
+/// Where [`from_human_time`] resolves an expression to a single point in time, this function
+/// returns the full period meant by it. "Next week" for example becomes the range from Monday to
+/// Sunday of next week, instead of the day exactly seven days from `now`.
+///
+/// # Parameters
+///
+/// - `str`: A human-readable date/time string (e.g., "last month", "this year", "Q2").
+/// - `now`: The reference `NaiveDateTime` representing the current time, used for resolving
+///   relative expressions like "yesterday" or "next week".
+///
+/// # Returns
+///
+/// - `Ok(ParseRange::Date(start, end))` if the input refers to one or more whole days. Both
+///   `start` and `end` are part of the period, so "yesterday" returns the same date twice.
+/// - `Ok(ParseRange::DateTime(start, end))` or `Ok(ParseRange::Time(start, end))` if the input
+///   refers to a time of day. Single points in time return a range starting and ending on them.
+/// - `Err(ParseError)` if parsing fails due to an unrecognized or invalid format.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use human_date_parser::{from_human_period, ParseRange};
+///
+/// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let period = from_human_period("Last month", now).unwrap();
+///
+/// assert_eq!(
+///     period,
+///     ParseRange::Date(
+///         NaiveDate::from_ymd_opt(2009, 12, 1).unwrap(),
+///         NaiveDate::from_ymd_opt(2009, 12, 31).unwrap(),
+///     )
+/// );
+/// ```
+pub fn from_human_period(str: &str, now: NaiveDateTime) -> Result<ParseRange, ParseError> {
+    Parser::new().parse_period(str, now)
 }
 
-fn parse_human_time(parsed: ast::HumanTime, now: NaiveDateTime) -> Result<ParseResult, ParseError> {
+/// Parses a human-readable description of something that happens repeatedly.
+///
+/// Supported are sets of weekdays ("every Monday and Friday", "every weekday"), intervals
+/// ("every 2 weeks", "every other day", "hourly"), days of the month ("on the 1st of each
+/// month") and weekdays of the month ("every last Friday of the month"). These can be followed by
+/// a time of day ("at 9" or "at 17:30") and a limit ("until 2025-12-31" or "for 10 times").
+///
+/// # Parameters
+///
+/// - `str`: A human-readable recurrence (e.g., "every Monday at 9", "every 2 weeks").
+/// - `now`: The reference `NaiveDateTime` representing the current time. The recurrence starts
+///   here, and relative limits like "until Friday" are resolved from it.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use human_date_parser::from_human_recurrence;
+///
+/// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let recurrence = from_human_recurrence("Every Monday at 9", now).unwrap();
+/// let mut occurrences = recurrence.occurrences_after(now);
+///
+/// assert_eq!(
+///     occurrences.next(),
+///     NaiveDate::from_ymd_opt(2010, 1, 4).unwrap().and_hms_opt(9, 0, 0)
+/// );
+/// assert_eq!(
+///     occurrences.next(),
+///     NaiveDate::from_ymd_opt(2010, 1, 11).unwrap().and_hms_opt(9, 0, 0)
+/// );
+/// ```
+pub fn from_human_recurrence(str: &str, now: NaiveDateTime) -> Result<Recurrence, ParseError> {
+    Parser::new().parse_recurrence(str, now)
+}
+
+/// Finds every date, time, range and duration in a piece of text, like "next Friday at 15:00" in
+/// "Let's meet next Friday at 15:00 in room 4".
+///
+/// Words that are only dates in some contexts, like "may" or "sun", are not picked up on their
+/// own. See [`Parser::extract`] to use other [`ParseOptions`].
+///
+/// # Parameters
+///
+/// - `text`: Any text that may contain dates or times.
+/// - `now`: The reference `NaiveDateTime` representing the current time, used for resolving
+///   relative expressions like "tomorrow" or "in 2 hours".
+///
+/// # Returns
+///
+/// Every expression found, in the order they appear in. Each comes with its byte range in `text`,
+/// the text itself and what it means.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, NaiveDate};
+/// use human_date_parser::{extract_human_times, ExtractedValue, ParseResult};
+///
+/// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let text = "Let's meet next Friday at 15:00 in room 4 for 2 hours.";
+/// let found = extract_human_times(text, now);
+///
+/// assert_eq!(found[0].text, "next Friday at 15:00");
+/// assert_eq!(found[0].span, 11..31);
+/// assert_eq!(
+///     found[0].value,
+///     ExtractedValue::Time(ParseResult::DateTime(
+///         NaiveDate::from_ymd_opt(2010, 1, 8).unwrap().and_hms_opt(15, 0, 0).unwrap()
+///     ))
+/// );
+/// assert_eq!(found[1].text, "2 hours");
+/// assert_eq!(found[1].value, ExtractedValue::Duration(Duration::hours(2)));
+/// ```
+pub fn extract_human_times(text: &str, now: NaiveDateTime) -> Vec<Extracted> {
+    Parser::new().extract(text, now)
+}
+
+fn parse_human_period(
+    parsed: ast::HumanTime,
+    now: NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<ParseRange, ParseError> {
+    match parsed {
+        ast::HumanTime::Date(date) => parse_date_period(date, &now, options)
+            .map(|(start, end)| ParseRange::Date(start, end))
+            .map_err(|err| ParseError::ProccessingErrors(vec![err])),
+        parsed => parse_human_time(parsed, now, options).map(|result| match result {
+            ParseResult::DateTime(datetime) => ParseRange::DateTime(datetime, datetime),
+            ParseResult::Date(date) => ParseRange::Date(date, date),
+            ParseResult::Time(time) => ParseRange::Time(time, time),
+            ParseResult::Range(range) => range,
+        }),
+    }
+}
+
+fn parse_human_time(
+    parsed: ast::HumanTime,
+    now: NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<ParseResult, ParseError> {
     match parsed {
         ast::HumanTime::DateTime(date_time) => {
-            parse_date_time(date_time, &now).map(|dt| ParseResult::DateTime(dt))
+            parse_date_time(date_time, &now, options).map(ParseResult::DateTime)
         }
-        ast::HumanTime::Date(date) => parse_date(date, &now)
-            .map(|date| ParseResult::Date(date))
+        ast::HumanTime::Date(date) => parse_date(date, &now, options)
+            .map(ParseResult::Date)
             .map_err(|err| ParseError::ProccessingErrors(vec![err])),
         ast::HumanTime::Time(time) => parse_time(time)
-            .map(|time| ParseResult::Time(time))
+            .and_then(|time| resolve_time(time, &now, options.prefer))
             .map_err(|err| ParseError::ProccessingErrors(vec![err])),
-        ast::HumanTime::In(in_ast) => parse_in(in_ast, &now)
-            .map(|time| ParseResult::DateTime(time))
+        ast::HumanTime::In(in_ast) => parse_in(in_ast, &now, options)
+            .map(ParseResult::DateTime)
             .map_err(|err| ParseError::ProccessingErrors(vec![err])),
-        ast::HumanTime::Ago(ago) => parse_ago(ago, &now)
-            .map(|time| ParseResult::DateTime(time))
+        ast::HumanTime::Ago(ago) => parse_ago(ago, &now, options)
+            .map(ParseResult::DateTime)
             .map_err(|err| ParseError::ProccessingErrors(vec![err])),
         ast::HumanTime::Now => Ok(ParseResult::DateTime(now)),
+        ast::HumanTime::Range(range) => parse_range(range, &now, options).map(ParseResult::Range),
     }
 }
 
-fn parse_date_time(date_time: DateTime, now: &NaiveDateTime) -> Result<NaiveDateTime, ParseError> {
-    let date = parse_date(date_time.date, now);
+fn parse_date_time(
+    date_time: DateTime,
+    now: &NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<NaiveDateTime, ParseError> {
+    let date = parse_date(date_time.date, now, options);
     let time = parse_time(date_time.time);
 
     match (date, time) {
@@ -171,8 +530,25 @@ fn parse_date_time(date_time: DateTime, now: &NaiveDateTime) -> Result<NaiveDate
     }
 }
 
-fn parse_date(date: Date, now: &NaiveDateTime) -> Result<NaiveDate, ProcessingError> {
+fn parse_date(
+    date: Date,
+    now: &NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<NaiveDate, ProcessingError> {
     match date {
+        Date::Offset(duration, direction, date) => {
+            let date = parse_date(*date, now, options)?;
+            let direction = match direction {
+                OffsetDirection::After => Direction::Forwards,
+                OffsetDirection::Before => Direction::Backwards,
+            };
+            apply_duration(duration, date.and_time(NaiveTime::MIN), direction, options)
+                .map(|dt| dt.date())
+        }
+        Date::Named(name, year) => resolve_named(&name, year, None, now, options),
+        Date::RelativeNamed(relative, name) => {
+            resolve_named(&name, None, Some(relative), now, options)
+        }
         Date::Today => Ok(now.date()),
         Date::Tomorrow => {
             now.date()
@@ -199,23 +575,133 @@ fn parse_date(date: Date, now: &NaiveDateTime) -> Result<NaiveDate, ProcessingEr
                 })
         }
         Date::IsoDate(iso_date) => parse_iso_date(iso_date),
+        Date::Quarter(quarter) => first_day_of_quarter(quarter, now.year()),
+        Date::QuarterYear(quarter, year) => first_day_of_quarter(quarter, year as i32),
         Date::DayMonthYear(day, month, year) => parse_day_month_year(day, month, year as i32),
-        Date::DayMonth(day, month) => parse_day_month_year(day, month, now.year()),
-        Date::RelativeWeekWeekday(relative, weekday) => {
-            find_weekday_relative_week(relative, weekday.into(), now.date())
+        Date::DayMonth(day, month) => resolve_day_month(day, month, now, options.prefer),
+        Date::DottedDayMonth(day, month) => u8::try_from(month)
+            .ok()
+            .and_then(|number| Month::try_from(number).ok())
+            .ok_or(ProcessingError::InvalidDate {
+                year: now.year(),
+                month,
+                day,
+            })
+            .and_then(|month| resolve_day_month(day, month, now, options.prefer)),
+        Date::MonthYear(month, year) => parse_day_month_year(1, month, year as i32),
+        Date::Year(year) => parse_day_month_year(1, Month::January, year as i32),
+        Date::StartOfWeek(relative) => find_weekday_relative_week(
+            relative.unwrap_or(RelativeSpecifier::This),
+            options.week_start,
+            now.date(),
+            options.week_start,
+        ),
+        Date::EndOfWeek(relative) => find_weekday_relative_week(
+            relative.unwrap_or(RelativeSpecifier::This),
+            options.week_start.pred(),
+            now.date(),
+            options.week_start,
+        ),
+        Date::Weekend(relative) => {
+            find_days_block(&options.weekend, relative, now.date()).map(|(start, _)| start)
         }
-        Date::RelativeWeekday(relative, weekday) => {
-            find_weekday_relative(relative, weekday.into(), now.date())
+        Date::Workweek(relative) => {
+            find_days_block(&workdays(options), relative, now.date()).map(|(start, _)| start)
+        }
+        Date::Month(month) => resolve_month(None, month, now, options.prefer),
+        Date::RelativeMonth(relative, month) => {
+            resolve_month(Some(relative), month, now, options.prefer)
         }
+        Date::RelativeWeekWeekday(relative, weekday) => {
+            find_weekday_relative_week(relative, weekday.into(), now.date(), options.week_start)
+        }
+        Date::RelativeWeekday(relative, weekday) => find_weekday_by_policy(
+            relative,
+            weekday.into(),
+            now.date(),
+            options.weekday_policy,
+            options.week_start,
+        ),
         Date::RelativeTimeUnit(relative, time_unit) => {
-            Ok(relative_date_time_unit(relative, time_unit, now.clone())?.date())
+            Ok(relative_date_time_unit(relative, time_unit, *now, options)?.date())
         }
         Date::UpcomingWeekday(weekday) => {
-            find_weekday_relative(RelativeSpecifier::Next, weekday.into(), now.date())
+            let relative = match options.prefer {
+                Preference::Past => RelativeSpecifier::Last,
+                Preference::Current | Preference::Future => RelativeSpecifier::Next,
+            };
+            find_weekday_relative(relative, weekday.into(), now.date())
         }
     }
 }
 
+fn parse_date_period(
+    date: Date,
+    now: &NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    match date {
+        Date::RelativeTimeUnit(relative, time_unit) => {
+            let date = relative_date_time_unit(relative, time_unit, *now, options)?.date();
+            period_containing(date, time_unit, options.week_start)
+        }
+        Date::Weekend(relative) => find_days_block(&options.weekend, relative, now.date()),
+        Date::Workweek(relative) => find_days_block(&workdays(options), relative, now.date()),
+        Date::Year(_) => period_containing(
+            parse_date(date, now, options)?,
+            TimeUnit::Year,
+            options.week_start,
+        ),
+        Date::MonthYear(_, _) | Date::Month(_) | Date::RelativeMonth(_, _) => period_containing(
+            parse_date(date, now, options)?,
+            TimeUnit::Month,
+            options.week_start,
+        ),
+        Date::Quarter(_) | Date::QuarterYear(_, _) => period_containing(
+            parse_date(date, now, options)?,
+            TimeUnit::Quarter,
+            options.week_start,
+        ),
+        date => parse_date(date, now, options).map(|date| (date, date)),
+    }
+}
+
+/// Finds the first and last day of the year, quarter, month or week `date` falls into. Weeks
+/// start on `week_start`.
+fn period_containing(
+    date: NaiveDate,
+    time_unit: TimeUnit,
+    week_start: Weekday,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    let start = match time_unit {
+        TimeUnit::Year => date.with_ordinal(1),
+        TimeUnit::Quarter => NaiveDate::from_ymd_opt(date.year(), date.month0() / 3 * 3 + 1, 1),
+        TimeUnit::Month => date.with_day(1),
+        TimeUnit::Week => date.checked_sub_days(Days::new(days_into_week(date, week_start))),
+        TimeUnit::BusinessDay
+        | TimeUnit::Day
+        | TimeUnit::Hour
+        | TimeUnit::Minute
+        | TimeUnit::Second => Some(date),
+    };
+    let end = start.and_then(|start| match time_unit {
+        TimeUnit::Year => start.checked_add_months(Months::new(12))?.pred_opt(),
+        TimeUnit::Quarter => start.checked_add_months(Months::new(3))?.pred_opt(),
+        TimeUnit::Month => start.checked_add_months(Months::new(1))?.pred_opt(),
+        TimeUnit::Week => start.checked_add_days(Days::new(6)),
+        TimeUnit::BusinessDay
+        | TimeUnit::Day
+        | TimeUnit::Hour
+        | TimeUnit::Minute
+        | TimeUnit::Second => Some(start),
+    });
+
+    start.zip(end).ok_or(ProcessingError::PeriodOutOfRange {
+        unit: format!("{time_unit:?}").to_lowercase(),
+        date,
+    })
+}
+
 fn parse_iso_date(iso_date: IsoDate) -> Result<NaiveDate, ProcessingError> {
     let (year, month, day) = (iso_date.year as i32, iso_date.month, iso_date.day);
     NaiveDate::from_ymd_opt(year, month, day).ok_or(ProcessingError::InvalidDate {
@@ -225,6 +711,15 @@ fn parse_iso_date(iso_date: IsoDate) -> Result<NaiveDate, ProcessingError> {
     })
 }
 
+fn first_day_of_quarter(quarter: u32, year: i32) -> Result<NaiveDate, ProcessingError> {
+    let month = (quarter - 1) * 3 + 1;
+    NaiveDate::from_ymd_opt(year, month, 1).ok_or(ProcessingError::InvalidDate {
+        year,
+        month,
+        day: 1,
+    })
+}
+
 fn parse_day_month_year(day: u32, month: Month, year: i32) -> Result<NaiveDate, ProcessingError> {
     let month = month.number_from_month();
     NaiveDate::from_ymd_opt(year, month, day).ok_or(ProcessingError::InvalidDate {
@@ -234,6 +729,220 @@ fn parse_day_month_year(day: u32, month: Month, year: i32) -> Result<NaiveDate,
     })
 }
 
+/// Finds the date for a day and month without a year, in the current year unless `prefer` says
+/// otherwise. Years in which the date does not exist, like February 29th, are skipped.
+fn resolve_day_month(
+    day: u32,
+    month: Month,
+    now: &NaiveDateTime,
+    prefer: Preference,
+) -> Result<NaiveDate, ProcessingError> {
+    let this_year = parse_day_month_year(day, month, now.year());
+    let years: Vec<i32> = match prefer {
+        Preference::Current => return this_year,
+        Preference::Future => (0..=8).map(|offset| now.year() + offset).collect(),
+        Preference::Past => (0..=8).map(|offset| now.year() - offset).collect(),
+    };
+
+    years
+        .into_iter()
+        .filter_map(|year| parse_day_month_year(day, month, year).ok())
+        .find(|date| match prefer {
+            Preference::Future => *date >= now.date(),
+            _ => *date <= now.date(),
+        })
+        .map_or(this_year, Ok)
+}
+
+/// Finds the date of an anchor or holiday, given by its name and optionally a year or specifier.
+/// Anchors win over holidays of the same name and can not be combined with a year or specifier.
+fn resolve_named(
+    name: &str,
+    year: Option<u32>,
+    relative: Option<RelativeSpecifier>,
+    now: &NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<NaiveDate, ProcessingError> {
+    if let Some(anchor) = options.anchor(name) {
+        return match (year, relative) {
+            (None, None) => Ok(anchor.resolve(*now)),
+            _ => Err(ProcessingError::AnchorWithSpecifier {
+                name: name.to_string(),
+            }),
+        };
+    }
+
+    let holiday = find_holiday(name, options)?;
+    match year {
+        Some(year) => holiday
+            .date_in(year as i32)
+            .ok_or_else(|| ProcessingError::NoDateInYear {
+                name: holiday.name().to_string(),
+                year: year as i32,
+            }),
+        None => resolve_holiday(holiday, relative, now, options.prefer),
+    }
+}
+
+fn find_holiday<'a>(name: &str, options: &'a ParseOptions) -> Result<&'a Holiday, ProcessingError> {
+    options
+        .holidays
+        .find(name)
+        .ok_or_else(|| ProcessingError::UnknownName {
+            name: name.to_string(),
+        })
+}
+
+/// Finds the date of a holiday given without a year. A bare "Christmas" and "this Christmas" lie in
+/// the current year, unless `prefer` says otherwise for the former. "Next Christmas" and "last
+/// Christmas" are the nearest ones after and before today.
+fn resolve_holiday(
+    holiday: &Holiday,
+    relative: Option<RelativeSpecifier>,
+    now: &NaiveDateTime,
+    prefer: Preference,
+) -> Result<NaiveDate, ProcessingError> {
+    let today = now.date();
+    let future = || (0..=8).filter_map(|offset| holiday.date_in(now.year() + offset));
+    let past = || (0..=8).filter_map(|offset| holiday.date_in(now.year() - offset));
+
+    let date = match (relative, prefer) {
+        (Some(RelativeSpecifier::This), _) | (None, Preference::Current) => {
+            holiday.date_in(now.year())
+        }
+        (Some(RelativeSpecifier::Next), _) => future().find(|date| *date > today),
+        (None, Preference::Future) => future().find(|date| *date >= today),
+        (Some(RelativeSpecifier::Last), _) => past().find(|date| *date < today),
+        (None, Preference::Past) => past().find(|date| *date <= today),
+    };
+
+    date.ok_or_else(|| ProcessingError::NoDateInYear {
+        name: holiday.name().to_string(),
+        year: now.year(),
+    })
+}
+
+/// Finds the first day of a month given without a year. "This March" and a bare "March" lie in the
+/// current year, unless `prefer` says otherwise for the latter. "Next March" and "last March"
+/// are the nearest ones after and before the current month.
+fn resolve_month(
+    relative: Option<RelativeSpecifier>,
+    month: Month,
+    now: &NaiveDateTime,
+    prefer: Preference,
+) -> Result<NaiveDate, ProcessingError> {
+    let (current, target) = (now.month(), month.number_from_month());
+    let years = match (relative, prefer) {
+        (Some(RelativeSpecifier::This), _) => 0,
+        (Some(RelativeSpecifier::Next), _) if target > current => 0,
+        (Some(RelativeSpecifier::Next), _) => 1,
+        (Some(RelativeSpecifier::Last), _) if target < current => 0,
+        (Some(RelativeSpecifier::Last), _) => -1,
+        (None, Preference::Future) if target < current => 1,
+        (None, Preference::Past) if target > current => -1,
+        (None, _) => 0,
+    };
+
+    parse_day_month_year(1, month, now.year() + years)
+}
+
+fn all_weekdays() -> impl Iterator<Item = Weekday> {
+    std::iter::successors(Some(Weekday::Mon), |day| Some(day.succ())).take(7)
+}
+
+/// The days of the week that are not part of the weekend.
+fn workdays(options: &ParseOptions) -> Vec<Weekday> {
+    all_weekdays()
+        .filter(|day| !options.weekend.contains(day))
+        .collect()
+}
+
+/// Finds the first and last day of a block of consecutive `days`, like a weekend. Without a
+/// specifier or with "this" it is the block `now` lies in, or the upcoming one. "Next" is the block
+/// after that and "last" the one that ended most recently.
+fn find_days_block(
+    days: &[Weekday],
+    relative: Option<RelativeSpecifier>,
+    now: NaiveDate,
+) -> Result<(NaiveDate, NaiveDate), ProcessingError> {
+    if days.is_empty() || all_weekdays().all(|day| days.contains(&day)) {
+        return Err(ProcessingError::InvalidWeekend);
+    }
+    let contains = |date: NaiveDate| days.contains(&date.weekday());
+    let step = |date: NaiveDate, forwards: bool| {
+        let next = if forwards {
+            date.succ_opt()
+        } else {
+            date.pred_opt()
+        };
+        next.ok_or(ProcessingError::PeriodOutOfRange {
+            unit: "weekend".to_string(),
+            date: now,
+        })
+    };
+    // Walks from `date` in one direction until `stop` is true, checking at most a week of days.
+    let walk = |mut date: NaiveDate, forwards: bool, stop: &dyn Fn(NaiveDate) -> bool| {
+        for _ in 0..7 {
+            if stop(date) {
+                return Ok(date);
+            }
+            date = step(date, forwards)?;
+        }
+        Err(ProcessingError::InvalidWeekend)
+    };
+    let block_start = |date: NaiveDate| -> Result<NaiveDate, ProcessingError> {
+        let before = walk(date, false, &|date| !contains(date))?;
+        step(before, true)
+    };
+    let block_end = |date: NaiveDate| -> Result<NaiveDate, ProcessingError> {
+        let after = walk(date, true, &|date| !contains(date))?;
+        step(after, false)
+    };
+
+    let current = walk(now, true, &contains)?;
+    let start = match relative {
+        None | Some(RelativeSpecifier::This) => block_start(current)?,
+        Some(RelativeSpecifier::Next) => {
+            let after = step(block_end(current)?, true)?;
+            walk(after, true, &contains)?
+        }
+        Some(RelativeSpecifier::Last) => {
+            let before = step(block_start(current)?, false)?;
+            let before = walk(before, false, &contains)?;
+            block_start(before)?
+        }
+    };
+
+    Ok((start, block_end(start)?))
+}
+
+/// Turns a bare time of day into a result. Unless `prefer` asks for the future or the past, the
+/// time is left without a date. Otherwise it is put on today or the day after or before.
+fn resolve_time(
+    time: NaiveTime,
+    now: &NaiveDateTime,
+    prefer: Preference,
+) -> Result<ParseResult, ProcessingError> {
+    let date = match prefer {
+        Preference::Current => return Ok(ParseResult::Time(time)),
+        Preference::Future if time < now.time() => now.date().checked_add_days(Days::new(1)),
+        Preference::Past if time > now.time() => now.date().checked_sub_days(Days::new(1)),
+        Preference::Future | Preference::Past => Some(now.date()),
+    };
+
+    date.map(|date| ParseResult::DateTime(date.and_time(time)))
+        .ok_or(match prefer {
+            Preference::Future => ProcessingError::AddToNow {
+                unit: "days".to_string(),
+                count: 1,
+            },
+            _ => ProcessingError::SubtractFromNow {
+                unit: "days".to_string(),
+                count: 1,
+            },
+        })
+}
+
 fn parse_time(time: Time) -> Result<NaiveTime, ProcessingError> {
     match time {
         Time::HourMinute(hour, minute) => NaiveTime::from_hms_opt(hour, minute, 0)
@@ -249,81 +958,245 @@ fn parse_time(time: Time) -> Result<NaiveTime, ProcessingError> {
     }
 }
 
-fn parse_in(in_ast: In, now: &NaiveDateTime) -> Result<NaiveDateTime, ProcessingError> {
-    let dt = now.clone();
-    apply_duration(in_ast.0, dt, Direction::Forwards)
+fn parse_in(
+    in_ast: In,
+    now: &NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<NaiveDateTime, ProcessingError> {
+    let dt = *now;
+    apply_duration(in_ast.0, dt, Direction::Forwards, options)
 }
 
-fn parse_ago(ago: Ago, now: &NaiveDateTime) -> Result<NaiveDateTime, ProcessingError> {
+fn parse_ago(
+    ago: Ago,
+    now: &NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<NaiveDateTime, ProcessingError> {
     match ago {
         Ago::AgoFromNow(ago) => {
-            let dt = now.clone();
-            apply_duration(ago, dt, Direction::Backwards)
+            let dt = *now;
+            apply_duration(ago, dt, Direction::Backwards, options)
         }
         Ago::AgoFromTime(ago, time) => {
-            let human_time = parse_human_time(*time, now.clone())
+            let human_time = parse_human_time(*time, *now, options)
                 .map_err(|e| ProcessingError::InnerHumanTimeParse(Box::new(e)))?;
-            let dt = match human_time {
-                ParseResult::DateTime(dt) => dt,
-                ParseResult::Date(date) => NaiveDateTime::new(date, now.time()),
-                ParseResult::Time(time) => NaiveDateTime::new(now.date(), time),
+            let dt = human_time.to_datetime(*now, DefaultTime::Now).ok_or(
+                ProcessingError::InnerHumanTimeParse(Box::new(
+                    InternalError::UnexpectedRange.into(),
+                )),
+            )?;
+            apply_duration(ago, dt, Direction::Backwards, options)
+        }
+    }
+}
+
+fn parse_range(
+    range: Range,
+    now: &NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<ParseRange, ParseError> {
+    let prefer = options.prefer;
+    let is_day_month = |bound: &RangeBound| {
+        matches!(bound, RangeBound::HumanTime(human_time)
+            if matches!(**human_time, ast::HumanTime::Date(Date::DayMonth(_, _) | Date::DottedDayMonth(_, _))))
+    };
+    let day_months = is_day_month(&range.start) && is_day_month(&range.end);
+
+    // The bounds are resolved around `now`, and the preference is applied to the range as a
+    // whole further down. Otherwise "March 3-7" could start and end in different years.
+    let options = &ParseOptions {
+        prefer: Preference::Current,
+        ..options.clone()
+    };
+    let (start, end) = match (range.start, range.end) {
+        (RangeBound::Hour(start), RangeBound::Hour(end)) => {
+            // "between 9 and 5" means 9:00 to 17:00, so read a smaller end as afternoon.
+            let end = if end < start && end < 12 {
+                end + 12
+            } else {
+                end
+            };
+            (parse_range_hour(start)?, parse_range_hour(end)?)
+        }
+        (start, end) => {
+            let start = parse_range_bound(start, now, options)?;
+            let resolved_end = parse_range_bound(end.clone(), now, options)?;
+            let range = build_range(start, resolved_end, day_months, prefer, now)?;
+
+            // An end like the "monday" in "from friday to monday" is resolved around `now` and can
+            // land before the start. It then refers to the first such day after the start.
+            match range_anchor(&range) {
+                Some(anchor) if range_ends_before_start(&range) => {
+                    let options = &ParseOptions {
+                        prefer: Preference::Future,
+                        ..options.clone()
+                    };
+                    (start, parse_range_bound(end, &anchor, options)?)
+                }
+                _ => (start, resolved_end),
+            }
+        }
+    };
+
+    let range = build_range(start, end, day_months, prefer, now)?;
+    if range_ends_before_start(&range) {
+        return Err(ParseError::ProccessingErrors(vec![
+            ProcessingError::RangeEndBeforeStart {
+                start: range.start(),
+                end: range.end(),
+            },
+        ]));
+    }
+
+    Ok(range)
+}
+
+fn build_range(
+    start: ParseResult,
+    end: ParseResult,
+    day_months: bool,
+    prefer: Preference,
+    now: &NaiveDateTime,
+) -> Result<ParseRange, ParseError> {
+    Ok(match (start, end) {
+        (ParseResult::Date(start), ParseResult::Date(end)) if day_months => {
+            let years = match prefer {
+                Preference::Future if end < now.date() => 1,
+                Preference::Past if start > now.date() => -1,
+                _ => 0,
             };
-            apply_duration(ago, dt, Direction::Backwards)
+            let shift = |date: NaiveDate| {
+                date.with_year(date.year() + years)
+                    .ok_or(ProcessingError::InvalidDate {
+                        year: date.year() + years,
+                        month: date.month(),
+                        day: date.day(),
+                    })
+            };
+            let shifted = shift(start).and_then(|start| Ok((start, shift(end)?)));
+            let (start, end) = shifted.map_err(|err| ParseError::ProccessingErrors(vec![err]))?;
+            ParseRange::Date(start, end)
         }
+        (ParseResult::Date(start), ParseResult::Date(end)) => ParseRange::Date(start, end),
+        (ParseResult::Time(start), ParseResult::Time(end)) => ParseRange::Time(start, end),
+        (start, end) => ParseRange::DateTime(
+            range_start_date_time(start, &end, now)?,
+            range_end_date_time(end, &start, now)?,
+        ),
+    })
+}
+
+fn range_ends_before_start(range: &ParseRange) -> bool {
+    match *range {
+        ParseRange::DateTime(start, end) => end < start,
+        ParseRange::Date(start, end) => end < start,
+        ParseRange::Time(start, end) => end < start,
+    }
+}
+
+/// The point in time an end bound is resolved around when it would otherwise lie before the start.
+/// Ranges of bare times have no date to move the end to, so they have none.
+fn range_anchor(range: &ParseRange) -> Option<NaiveDateTime> {
+    match *range {
+        ParseRange::DateTime(start, _) => Some(start),
+        ParseRange::Date(start, _) => Some(start.and_time(NaiveTime::MIN)),
+        ParseRange::Time(_, _) => None,
+    }
+}
+
+fn parse_range_bound(
+    bound: RangeBound,
+    now: &NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<ParseResult, ParseError> {
+    match bound {
+        RangeBound::HumanTime(human_time) => parse_human_time(*human_time, *now, options),
+        RangeBound::Hour(hour) => parse_range_hour(hour),
     }
 }
 
-#[derive(PartialEq, Eq)]
+fn parse_range_hour(hour: u32) -> Result<ParseResult, ParseError> {
+    NaiveTime::from_hms_opt(hour, 0, 0)
+        .map(ParseResult::Time)
+        .ok_or(ParseError::ProccessingErrors(vec![
+            ProcessingError::InvalidHour { hour },
+        ]))
+}
+
+/// Turns the start of a range into a `NaiveDateTime`, using the end of the range to fill in
+/// what is missing. Dates start at midnight and times fall on the date of the other bound.
+fn range_start_date_time(
+    start: ParseResult,
+    end: &ParseResult,
+    now: &NaiveDateTime,
+) -> Result<NaiveDateTime, ParseError> {
+    match start {
+        ParseResult::DateTime(dt) => Ok(dt),
+        ParseResult::Date(date) => Ok(date.and_time(NaiveTime::MIN)),
+        ParseResult::Time(time) => Ok(range_bound_date(end, now).and_time(time)),
+        ParseResult::Range(_) => Err(InternalError::UnexpectedRange.into()),
+    }
+}
+
+/// Turns the end of a range into a `NaiveDateTime`, using the start of the range to fill in
+/// what is missing. Dates end at the last second of the day, as date ranges include their end.
+fn range_end_date_time(
+    end: ParseResult,
+    start: &ParseResult,
+    now: &NaiveDateTime,
+) -> Result<NaiveDateTime, ParseError> {
+    match end {
+        ParseResult::DateTime(dt) => Ok(dt),
+        ParseResult::Date(date) => Ok(date.and_time(end_of_day())),
+        ParseResult::Time(time) => Ok(range_bound_date(start, now).and_time(time)),
+        ParseResult::Range(_) => Err(InternalError::UnexpectedRange.into()),
+    }
+}
+
+fn range_bound_date(bound: &ParseResult, now: &NaiveDateTime) -> NaiveDate {
+    match bound {
+        ParseResult::DateTime(dt) => dt.date(),
+        ParseResult::Date(date) => *date,
+        ParseResult::Time(_) | ParseResult::Range(_) => now.date(),
+    }
+}
+
+fn end_of_day() -> NaiveTime {
+    NaiveTime::from_hms_opt(23, 59, 59).expect("23:59:59 is a valid time")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Direction {
     Forwards,
     Backwards,
 }
 
+/// The length of a duration, counted from `now`, as months and years differ in length.
+fn duration_from(
+    duration: AstDuration,
+    now: NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<ChronoDuration, ProcessingError> {
+    apply_duration(duration, now, Direction::Forwards, options).map(|then| then - now)
+}
+
 fn apply_duration(
     duration: AstDuration,
     mut dt: NaiveDateTime,
     direction: Direction,
+    options: &ParseOptions,
 ) -> Result<NaiveDateTime, ProcessingError> {
+    let overflow = options.month_overflow;
     for quant in duration.0 {
         match quant {
             Quantifier::Year(years) => {
-                let years = years as i32;
-                if direction == Direction::Forwards {
-                    dt = dt
-                        .with_year(dt.year() + years)
-                        .ok_or(ProcessingError::InvalidDate {
-                            year: dt.year() + years,
-                            month: dt.month(),
-                            day: dt.day(),
-                        })?;
-                } else {
-                    dt = dt
-                        .with_year(dt.year() - years)
-                        .ok_or(ProcessingError::InvalidDate {
-                            year: dt.year() - years,
-                            month: dt.month(),
-                            day: dt.day(),
-                        })?;
-                }
+                dt = shift_months(dt, years, 12, "years", direction, overflow)?
+            }
+            Quantifier::Quarter(quarters) => {
+                dt = shift_months(dt, quarters, 3, "quarters", direction, overflow)?
             }
             Quantifier::Month(months) => {
-                if direction == Direction::Forwards {
-                    dt = dt.checked_add_months(Months::new(months)).ok_or(
-                        ProcessingError::AddToDate {
-                            unit: "months".to_string(),
-                            count: months,
-                            date: dt,
-                        },
-                    )?
-                } else {
-                    dt = dt.checked_sub_months(Months::new(months)).ok_or(
-                        ProcessingError::SubtractFromDate {
-                            unit: "months".to_string(),
-                            count: months,
-                            date: dt,
-                        },
-                    )?
-                }
+                dt = shift_months(dt, months, 1, "months", direction, overflow)?
             }
             Quantifier::Week(weeks) => {
                 if direction == Direction::Forwards {
@@ -344,6 +1217,9 @@ fn apply_duration(
                     )?
                 }
             }
+            Quantifier::BusinessDay(days) => {
+                dt = add_business_days(dt, days, direction, &*options.calendar())?
+            }
             Quantifier::Day(days) => {
                 if direction == Direction::Forwards {
                     dt = dt.checked_add_days(Days::new(days as u64)).ok_or(
@@ -364,25 +1240,31 @@ fn apply_duration(
                 }
             }
             Quantifier::Hour(hours) => {
-                if direction == Direction::Forwards {
-                    dt = dt + ChronoDuration::hours(hours as i64)
-                } else {
-                    dt = dt - ChronoDuration::hours(hours as i64)
-                }
+                dt = shift_time(
+                    dt,
+                    ChronoDuration::hours(hours as i64),
+                    hours,
+                    "hours",
+                    direction,
+                )?
             }
             Quantifier::Minute(minutes) => {
-                if direction == Direction::Forwards {
-                    dt = dt + ChronoDuration::minutes(minutes as i64)
-                } else {
-                    dt = dt - ChronoDuration::minutes(minutes as i64)
-                }
+                dt = shift_time(
+                    dt,
+                    ChronoDuration::minutes(minutes as i64),
+                    minutes,
+                    "minutes",
+                    direction,
+                )?
             }
             Quantifier::Second(seconds) => {
-                if direction == Direction::Forwards {
-                    dt = dt + ChronoDuration::seconds(seconds as i64)
-                } else {
-                    dt = dt - ChronoDuration::seconds(seconds as i64)
-                }
+                dt = shift_time(
+                    dt,
+                    ChronoDuration::seconds(seconds as i64),
+                    seconds,
+                    "seconds",
+                    direction,
+                )?
             }
         };
     }
@@ -390,26 +1272,147 @@ fn apply_duration(
     Ok(dt)
 }
 
+/// Moves `dt` by `duration`, which is `count` of `unit`.
+fn shift_time(
+    dt: NaiveDateTime,
+    duration: ChronoDuration,
+    count: u32,
+    unit: &str,
+    direction: Direction,
+) -> Result<NaiveDateTime, ProcessingError> {
+    match direction {
+        Direction::Forwards => dt
+            .checked_add_signed(duration)
+            .ok_or(ProcessingError::AddToDate {
+                unit: unit.to_string(),
+                count,
+                date: dt,
+            }),
+        Direction::Backwards => {
+            dt.checked_sub_signed(duration)
+                .ok_or(ProcessingError::SubtractFromDate {
+                    unit: unit.to_string(),
+                    count,
+                    date: dt,
+                })
+        }
+    }
+}
+
+/// Moves `dt` by `count` times `months_per_unit` months. If the day of the month does not exist in
+/// the month it ends up in, like January 31st plus a month, `overflow` decides what happens.
+fn shift_months(
+    dt: NaiveDateTime,
+    count: u32,
+    months_per_unit: u32,
+    unit: &str,
+    direction: Direction,
+    overflow: MonthOverflow,
+) -> Result<NaiveDateTime, ProcessingError> {
+    let out_of_range = || match direction {
+        Direction::Forwards => ProcessingError::AddToDate {
+            unit: unit.to_string(),
+            count,
+            date: dt,
+        },
+        Direction::Backwards => ProcessingError::SubtractFromDate {
+            unit: unit.to_string(),
+            count,
+            date: dt,
+        },
+    };
+
+    let months = count as i64 * months_per_unit as i64;
+    let months = match direction {
+        Direction::Forwards => months,
+        Direction::Backwards => -months,
+    };
+    let total = dt.year() as i64 * 12 + dt.month0() as i64 + months;
+    let year = i32::try_from(total.div_euclid(12)).map_err(|_| out_of_range())?;
+    let month = total.rem_euclid(12) as u32 + 1;
+
+    let last_day = (28..=31)
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .ok_or_else(out_of_range)?;
+    let day = dt.day();
+    let date = if day <= last_day.day() {
+        last_day.with_day(day)
+    } else {
+        match overflow {
+            MonthOverflow::Clamp => Some(last_day),
+            MonthOverflow::RollOver => {
+                last_day.checked_add_days(Days::new((day - last_day.day()) as u64))
+            }
+            MonthOverflow::Error => {
+                return Err(ProcessingError::InvalidDate { year, month, day });
+            }
+        }
+    };
+
+    date.map(|date| date.and_time(dt.time()))
+        .ok_or_else(out_of_range)
+}
+
 fn relative_date_time_unit(
     relative: RelativeSpecifier,
     time_unit: TimeUnit,
     now: NaiveDateTime,
+    options: &ParseOptions,
 ) -> Result<NaiveDateTime, ProcessingError> {
     let quantifier = match time_unit {
         TimeUnit::Year => Quantifier::Year(1),
+        TimeUnit::Quarter => Quantifier::Quarter(1),
         TimeUnit::Month => Quantifier::Month(1),
         TimeUnit::Week => Quantifier::Week(1),
         TimeUnit::Day => Quantifier::Day(1),
+        TimeUnit::BusinessDay => Quantifier::BusinessDay(1),
         TimeUnit::Hour | TimeUnit::Minute | TimeUnit::Second => {
-            unreachable!("Non-date time units should never be used in this function.")
+            return Err(ProcessingError::RelativeTimeUnit {
+                unit: format!("{time_unit:?}").to_lowercase(),
+            });
         }
     };
 
-
     match relative {
+        RelativeSpecifier::This if time_unit == TimeUnit::BusinessDay => {
+            // "This business day" is today, unless today is not one.
+            if options.calendar().is_business_day(now.date()) {
+                Ok(now)
+            } else {
+                add_business_days(now, 1, Direction::Forwards, &*options.calendar())
+            }
+        }
         RelativeSpecifier::This => Ok(now),
-        RelativeSpecifier::Next => apply_duration(AstDuration(vec![quantifier]), now, Direction::Forwards),
-        RelativeSpecifier::Last => apply_duration(AstDuration(vec![quantifier]), now, Direction::Backwards),
+        RelativeSpecifier::Next => apply_duration(
+            AstDuration(vec![quantifier]),
+            now,
+            Direction::Forwards,
+            options,
+        ),
+        RelativeSpecifier::Last => apply_duration(
+            AstDuration(vec![quantifier]),
+            now,
+            Direction::Backwards,
+            options,
+        ),
+    }
+}
+
+/// Finds the weekday "this", "next" or "last" refers to, the way `policy` reads it.
+fn find_weekday_by_policy(
+    relative: RelativeSpecifier,
+    weekday: Weekday,
+    now: NaiveDate,
+    policy: WeekdayPolicy,
+    week_start: Weekday,
+) -> Result<NaiveDate, ProcessingError> {
+    match policy {
+        WeekdayPolicy::CalendarWeek => {
+            find_weekday_relative_week(relative, weekday, now, week_start)
+        }
+        WeekdayPolicy::NearestUpcoming => find_weekday_relative(relative, weekday, now),
+        WeekdayPolicy::SkipOne => find_weekday_skipping_one(relative, weekday, now),
     }
 }
 
@@ -417,8 +1420,9 @@ fn find_weekday_relative_week(
     relative: RelativeSpecifier,
     weekday: Weekday,
     now: NaiveDate,
+    week_start: Weekday,
 ) -> Result<NaiveDate, ProcessingError> {
-    let day_offset = -(now.weekday().num_days_from_monday() as i64);
+    let day_offset = -(days_into_week(now, week_start) as i64);
     let week_offset = match relative {
         RelativeSpecifier::This => 0,
         RelativeSpecifier::Next => 1,
@@ -443,6 +1447,46 @@ fn find_weekday_relative_week(
     find_weekday_relative(RelativeSpecifier::This, weekday, now)
 }
 
+/// Counts the days between the start of the week `date` lies in and `date` itself.
+fn days_into_week(date: NaiveDate, week_start: Weekday) -> u64 {
+    let days = date.weekday().num_days_from_monday() + 7 - week_start.num_days_from_monday();
+    (days % 7) as u64
+}
+
+/// Finds the weekday one week further away than the nearest one, counting today. On a Friday,
+/// "next Saturday" is the Saturday of next week and "next Friday" is the one a week from today.
+fn find_weekday_skipping_one(
+    relative: RelativeSpecifier,
+    weekday: Weekday,
+    now: NaiveDate,
+) -> Result<NaiveDate, ProcessingError> {
+    let nearest = if now.weekday() == weekday {
+        now
+    } else {
+        find_weekday_relative(relative, weekday, now)?
+    };
+
+    match relative {
+        RelativeSpecifier::This => Ok(nearest),
+        RelativeSpecifier::Next => {
+            nearest
+                .checked_add_days(Days::new(7))
+                .ok_or(ProcessingError::AddToNow {
+                    unit: "days".to_string(),
+                    count: 7,
+                })
+        }
+        RelativeSpecifier::Last => {
+            nearest
+                .checked_sub_days(Days::new(7))
+                .ok_or(ProcessingError::SubtractFromNow {
+                    unit: "days".to_string(),
+                    count: 7,
+                })
+        }
+    }
+}
+
 fn find_weekday_relative(
     relative: RelativeSpecifier,
     weekday: Weekday,
@@ -451,7 +1495,7 @@ fn find_weekday_relative(
     match relative {
         RelativeSpecifier::This | RelativeSpecifier::Next => {
             if matches!(relative, RelativeSpecifier::This) && now.weekday() == weekday {
-                return Ok(now.clone());
+                return Ok(now);
             }
 
             let current_weekday = now.weekday().num_days_from_monday();
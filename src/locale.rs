@@ -0,0 +1,184 @@
+use chrono::{Month, Weekday};
+
+use crate::normalize::{normalize_str, Normalized};
+use crate::Unit;
+
+mod de;
+mod es;
+mod fr;
+
+/// The words of a language, and the English words of the grammar they stand for.
+///
+/// Input is translated word by word into English before it is parsed, so every language shares
+/// the same grammar and produces the same results. Words are matched as whole words, regardless of
+/// case and accents, and longer phrases win over shorter ones. Words without a translation are left alone,
+/// which means numbers, times like "17:00" and the names of holidays and anchors work in every
+/// language.
+///
+/// [`Locale::english`] has no words of its own and is used by default.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use human_date_parser::{Locale, Parser, Unit};
+///
+/// let dutch = Locale::new("nl")
+///     .weekday(Weekday::Fri, ["vrijdag"])
+///     .unit(Unit::Day, ["dag", "dagen"])
+///     .word("next", ["volgende"])
+///     .word("in", ["over"])
+///     .word("at", ["om"]);
+/// let parser = Parser::new().locale(dutch);
+/// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     parser.parse("Volgende vrijdag om 17:00", now).unwrap(),
+///     Parser::new().parse("Next Friday at 17:00", now).unwrap()
+/// );
+/// assert_eq!(
+///     parser.parse("Over 3 dagen", now).unwrap(),
+///     Parser::new().parse("In 3 days", now).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale {
+    name: String,
+    words: Vec<(String, String)>,
+}
+
+impl Locale {
+    /// Creates a locale without any words. `name` identifies it, like "de" or "pt-BR".
+    pub fn new(name: impl Into<String>) -> Self {
+        Locale {
+            name: name.into(),
+            words: Vec::new(),
+        }
+    }
+
+    /// The language the grammar is written in, which needs no translation.
+    pub fn english() -> Self {
+        Locale::new("en")
+    }
+
+    /// The name of the locale.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Adds names for a month, including short and declined forms.
+    pub fn month<S: Into<String>>(self, month: Month, names: impl IntoIterator<Item = S>) -> Self {
+        let english = month.name().to_lowercase();
+        self.word(english, names)
+    }
+
+    /// Adds names for a day of the week, including short and declined forms.
+    pub fn weekday<S: Into<String>>(
+        self,
+        weekday: Weekday,
+        names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let english = match weekday {
+            Weekday::Mon => "monday",
+            Weekday::Tue => "tuesday",
+            Weekday::Wed => "wednesday",
+            Weekday::Thu => "thursday",
+            Weekday::Fri => "friday",
+            Weekday::Sat => "saturday",
+            Weekday::Sun => "sunday",
+        };
+        self.word(english, names)
+    }
+
+    /// Adds names for a unit of time, in the singular and plural.
+    pub fn unit<S: Into<String>>(self, unit: Unit, names: impl IntoIterator<Item = S>) -> Self {
+        self.word(unit.name(), names)
+    }
+
+    /// Adds words that stand for an English word or phrase of the grammar. This covers everything
+    /// that is not a month, weekday or unit, like "next" and "last", connecting words like "at",
+    /// "in" and "ago", or whole phrases like "the day after tomorrow". Words that English does not
+    /// need, like some articles, can be dropped by passing an empty string as `english`.
+    pub fn word<S: Into<String>>(
+        mut self,
+        english: impl Into<String>,
+        names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let english = english.into();
+        self.words.extend(
+            names
+                .into_iter()
+                .map(|name| (normalize_str(&name.into(), true), english.clone())),
+        );
+        self
+    }
+
+    /// Every word of the language the locale knows, normalized and without accents.
+    pub(crate) fn words(&self) -> impl Iterator<Item = &str> {
+        self.words
+            .iter()
+            .flat_map(|(word, _)| word.split_whitespace())
+    }
+
+    /// Translates normalized input into the English the grammar understands.
+    pub(crate) fn translate(&self, input: &Normalized) -> Normalized {
+        if self.words.is_empty() {
+            return input.clone();
+        }
+        replace_words(&input.without_accents(), &self.words)
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::english()
+    }
+}
+
+/// Replaces every whole-word occurrence of the first part of a pair in `words` with its second
+/// part. Longer words are tried first, and anything wrapped in braces is left alone. Words replaced
+/// with nothing take the whitespace after them along.
+pub(crate) fn replace_words(input: &Normalized, words: &[(String, String)]) -> Normalized {
+    let mut words: Vec<&(String, String)> = words.iter().collect();
+    words.sort_by_key(|(word, _)| std::cmp::Reverse(word.len()));
+
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    let text = input.as_str();
+    let mut replaced = Normalized::with_capacity(text.len());
+    let mut offset = 0;
+    while let Some(c) = text[offset..].chars().next() {
+        let rest = &text[offset..];
+        if c == '{' {
+            let end = offset + rest.find('}').map_or(rest.len(), |end| end + 1);
+            replaced.push_from(input, offset..end);
+            offset = end;
+            continue;
+        }
+
+        let at_word_start = !is_word(replaced.as_str().chars().next_back());
+        let found = words.iter().find(|(word, _)| {
+            at_word_start
+                && rest.starts_with(word.as_str())
+                && !is_word(rest[word.len()..].chars().next())
+        });
+
+        match found {
+            Some((word, replacement)) => {
+                let end = offset + word.len();
+                replaced.push_str(replacement, input.original_range(offset..end));
+                offset = end;
+                if replacement.is_empty() {
+                    let rest = &text[offset..];
+                    offset += rest.len() - rest.trim_start().len();
+                }
+            }
+            None => {
+                let end = offset + c.len_utf8();
+                replaced.push_from(input, offset..end);
+                offset = end;
+            }
+        }
+    }
+
+    replaced
+}
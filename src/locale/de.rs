@@ -0,0 +1,144 @@
+use chrono::{Month, Weekday};
+
+use crate::{Locale, Unit};
+
+impl Locale {
+    /// German, as in "übermorgen", "nächsten Freitag um 14 Uhr", "vor 3 Tagen" or "24.12.2025".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use human_date_parser::{Locale, Parser};
+    ///
+    /// let parser = Parser::new().locale(Locale::german());
+    /// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     parser.parse("Nächsten Freitag um 14 Uhr", now).unwrap(),
+    ///     Parser::new().parse("Next Friday at 14:00", now).unwrap()
+    /// );
+    /// ```
+    pub fn german() -> Self {
+        Locale::new("de")
+            .month(Month::January, ["januar", "jänner", "jan", "jän"])
+            .month(Month::February, ["februar", "feber", "feb"])
+            .month(Month::March, ["märz", "maerz", "mär", "mrz"])
+            .month(Month::April, ["april", "apr"])
+            .month(Month::May, ["mai"])
+            .month(Month::June, ["juni", "jun"])
+            .month(Month::July, ["juli", "jul"])
+            .month(Month::August, ["august", "aug"])
+            .month(Month::September, ["september", "sep", "sept"])
+            .month(Month::October, ["oktober", "okt"])
+            .month(Month::November, ["november", "nov"])
+            .month(Month::December, ["dezember", "dez"])
+            .weekday(Weekday::Mon, ["montag"])
+            .weekday(Weekday::Tue, ["dienstag"])
+            .weekday(Weekday::Wed, ["mittwoch"])
+            .weekday(Weekday::Thu, ["donnerstag"])
+            .weekday(Weekday::Fri, ["freitag"])
+            .weekday(Weekday::Sat, ["samstag", "sonnabend"])
+            .weekday(Weekday::Sun, ["sonntag"])
+            .unit(Unit::Year, ["jahr", "jahre", "jahren", "jahres"])
+            .unit(Unit::Month, ["monat", "monate", "monaten", "monats"])
+            .unit(Unit::Week, ["woche", "wochen"])
+            .unit(Unit::Day, ["tag", "tage", "tagen", "tages"])
+            .unit(Unit::Hour, ["stunde", "stunden", "std"])
+            .unit(Unit::Minute, ["minute", "minuten", "min"])
+            .unit(Unit::Second, ["sekunde", "sekunden", "sek"])
+            .word("quarter", ["quartal", "quartale", "quartalen"])
+            .word(
+                "business day",
+                [
+                    "werktag",
+                    "werktage",
+                    "werktagen",
+                    "arbeitstag",
+                    "arbeitstage",
+                    "arbeitstagen",
+                ],
+            )
+            .word("weekend", ["wochenende"])
+            .word("workweek", ["arbeitswoche"])
+            .word("today", ["heute"])
+            .word("tomorrow", ["morgen"])
+            .word("overmorrow", ["übermorgen", "uebermorgen"])
+            .word("yesterday", ["gestern"])
+            .word("the day before yesterday", ["vorgestern"])
+            .word("now", ["jetzt"])
+            .word("new year's day", ["neujahr", "neujahrstag"])
+            .word("christmas eve", ["heiligabend", "heiliger abend"])
+            .word("christmas", ["weihnachten", "erster weihnachtstag"])
+            .word("good friday", ["karfreitag"])
+            .word("easter", ["ostern", "ostersonntag"])
+            .word("easter monday", ["ostermontag"])
+            .word("ascension day", ["christi himmelfahrt"])
+            .word("pentecost", ["pfingsten", "pfingstsonntag"])
+            .word("whit monday", ["pfingstmontag"])
+            // "Morgen" is both tomorrow and the morning.
+            .word("today morning", ["heute morgen"])
+            .word("yesterday morning", ["gestern morgen"])
+            .word("morning", ["früh", "morgens", "vormittag", "vormittags"])
+            .word("noon", ["mittag", "mittags"])
+            .word("afternoon", ["nachmittag", "nachmittags"])
+            .word("evening", ["abend", "abends"])
+            .word("night", ["nacht", "nachts"])
+            .word(
+                "next",
+                [
+                    "nächste",
+                    "nächsten",
+                    "nächster",
+                    "nächstes",
+                    "naechste",
+                    "naechsten",
+                    "kommende",
+                    "kommenden",
+                    "kommender",
+                    "kommendes",
+                ],
+            )
+            .word(
+                "last",
+                [
+                    "letzte",
+                    "letzten",
+                    "letzter",
+                    "letztes",
+                    "vergangene",
+                    "vergangenen",
+                    "vergangener",
+                    "vergangenes",
+                    "vorige",
+                    "vorigen",
+                    "voriger",
+                    "voriges",
+                ],
+            )
+            .word("this", ["diese", "diesen", "dieser", "dieses"])
+            // "Vor" followed by a duration means "ago", which the grammar also reads from "before".
+            .word("before", ["vor"])
+            .word("after", ["nach"])
+            .word("at", ["um", "gegen"])
+            .word("o'clock", ["uhr"])
+            .word("in", ["im"])
+            .word("and", ["und"])
+            .word("from", ["von", "ab"])
+            .word("until", ["bis"])
+            .word("between", ["zwischen"])
+            .word("a", ["ein", "eine", "einem", "einen", "einer", "eines"])
+            .word(
+                "every other",
+                ["jeden zweiten", "jede zweite", "jedes zweite"],
+            )
+            .word("every", ["jeden", "jede", "jedes", "alle"])
+            .word("daily", ["täglich"])
+            .word("weekly", ["wöchentlich"])
+            .word("monthly", ["monatlich"])
+            .word("quarterly", ["vierteljährlich", "quartalsweise"])
+            .word("yearly", ["jährlich"])
+            .word("hourly", ["stündlich"])
+            .word("", ["am", "der", "die", "das", "den", "dem", "des"])
+    }
+}
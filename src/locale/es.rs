@@ -0,0 +1,114 @@
+use chrono::{Month, Weekday};
+
+use crate::{Locale, Unit};
+
+impl Locale {
+    /// Spanish, as in "pasado mañana", "el próximo viernes a las 5", "hace 3 días" or "la semana
+    /// que viene".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use human_date_parser::{Locale, Parser};
+    ///
+    /// let parser = Parser::new().locale(Locale::spanish());
+    /// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     parser.parse("El próximo viernes a las 5", now).unwrap(),
+    ///     Parser::new().parse("Next Friday at 5:00", now).unwrap()
+    /// );
+    /// ```
+    pub fn spanish() -> Self {
+        Locale::new("es")
+            .month(Month::January, ["enero", "ene"])
+            .month(Month::February, ["febrero", "feb"])
+            .month(Month::March, ["marzo", "mar"])
+            .month(Month::April, ["abril", "abr"])
+            .month(Month::May, ["mayo", "may"])
+            .month(Month::June, ["junio", "jun"])
+            .month(Month::July, ["julio", "jul"])
+            .month(Month::August, ["agosto", "ago"])
+            .month(Month::September, ["septiembre", "setiembre", "sep", "sept"])
+            .month(Month::October, ["octubre", "oct"])
+            .month(Month::November, ["noviembre", "nov"])
+            .month(Month::December, ["diciembre", "dic"])
+            .weekday(Weekday::Mon, ["lunes"])
+            .weekday(Weekday::Tue, ["martes"])
+            .weekday(Weekday::Wed, ["miércoles"])
+            .weekday(Weekday::Thu, ["jueves"])
+            .weekday(Weekday::Fri, ["viernes"])
+            .weekday(Weekday::Sat, ["sábado", "sábados"])
+            .weekday(Weekday::Sun, ["domingo", "domingos"])
+            .unit(Unit::Year, ["año", "años"])
+            .unit(Unit::Month, ["mes", "meses"])
+            .unit(Unit::Week, ["semana", "semanas"])
+            .unit(Unit::Day, ["día", "días"])
+            .unit(Unit::Hour, ["hora", "horas"])
+            .unit(Unit::Minute, ["minuto", "minutos"])
+            .unit(Unit::Second, ["segundo", "segundos"])
+            .word("quarter", ["trimestre", "trimestres"])
+            .word(
+                "business day",
+                [
+                    "día hábil",
+                    "días hábiles",
+                    "día laborable",
+                    "días laborables",
+                ],
+            )
+            .word("weekend", ["fin de semana"])
+            .word("workweek", ["semana laboral"])
+            .word("today", ["hoy"])
+            .word("tomorrow", ["mañana"])
+            .word("overmorrow", ["pasado mañana"])
+            .word("yesterday", ["ayer"])
+            .word(
+                "the day before yesterday",
+                ["anteayer", "antier", "antes de ayer"],
+            )
+            .word("now", ["ahora"])
+            .word("new year's day", ["año nuevo"])
+            .word("new year's eve", ["nochevieja"])
+            .word("christmas eve", ["nochebuena"])
+            .word("christmas", ["navidad"])
+            .word("good friday", ["viernes santo"])
+            .word(
+                "easter",
+                ["pascua", "domingo de pascua", "domingo de resurrección"],
+            )
+            .word("easter monday", ["lunes de pascua"])
+            .word("pentecost", ["pentecostés"])
+            // "Mañana" is both tomorrow and the morning.
+            .word("tomorrow morning", ["mañana por la mañana"])
+            .word("this morning", ["esta mañana"])
+            .word("morning", ["por la mañana", "de la mañana"])
+            .word("noon", ["mediodía"])
+            .word("this afternoon", ["esta tarde"])
+            .word("afternoon", ["tarde", "por la tarde", "de la tarde"])
+            .word("this night", ["esta noche"])
+            .word("night", ["noche", "por la noche", "de la noche"])
+            .word("next", ["próximo", "próxima", "que viene", "siguiente"])
+            .word("last", ["pasado", "pasada", "último", "última"])
+            .word("this", ["este", "esta"])
+            // "Hace" goes before the duration, which the grammar reads from "before".
+            .word("before", ["hace", "antes de"])
+            .word("after", ["después de"])
+            .word("at", ["a las", "a la"])
+            .word("o'clock", ["en punto"])
+            .word("in", ["en", "dentro de"])
+            .word("and", ["y"])
+            .word("from", ["desde"])
+            .word("until", ["hasta"])
+            .word("between", ["entre"])
+            .word("a", ["un", "una", "uno"])
+            .word("every", ["cada", "todos los", "todas las"])
+            .word("daily", ["diario", "diariamente"])
+            .word("weekly", ["semanal", "semanalmente"])
+            .word("monthly", ["mensual", "mensualmente"])
+            .word("quarterly", ["trimestral", "trimestralmente"])
+            .word("yearly", ["anual", "anualmente"])
+            .word("", ["el", "la", "los", "las", "de", "del"])
+    }
+}
@@ -0,0 +1,107 @@
+use chrono::{Month, Weekday};
+
+use crate::{Locale, Unit};
+
+impl Locale {
+    /// French, as in "après-demain", "vendredi prochain à 17h", "il y a 3 jours" or "la semaine
+    /// dernière".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use human_date_parser::{Locale, Parser};
+    ///
+    /// let parser = Parser::new().locale(Locale::french());
+    /// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     parser.parse("Vendredi prochain à 17h", now).unwrap(),
+    ///     Parser::new().parse("Next Friday at 17:00", now).unwrap()
+    /// );
+    /// ```
+    pub fn french() -> Self {
+        Locale::new("fr")
+            .month(Month::January, ["janvier", "janv"])
+            .month(Month::February, ["février", "févr", "fév"])
+            .month(Month::March, ["mars"])
+            .month(Month::April, ["avril", "avr"])
+            .month(Month::May, ["mai"])
+            .month(Month::June, ["juin"])
+            .month(Month::July, ["juillet", "juil"])
+            .month(Month::August, ["août"])
+            .month(Month::September, ["septembre", "sept"])
+            .month(Month::October, ["octobre", "oct"])
+            .month(Month::November, ["novembre", "nov"])
+            .month(Month::December, ["décembre", "déc"])
+            .weekday(Weekday::Mon, ["lundi"])
+            .weekday(Weekday::Tue, ["mardi"])
+            .weekday(Weekday::Wed, ["mercredi"])
+            .weekday(Weekday::Thu, ["jeudi"])
+            .weekday(Weekday::Fri, ["vendredi"])
+            .weekday(Weekday::Sat, ["samedi"])
+            .weekday(Weekday::Sun, ["dimanche"])
+            .unit(Unit::Year, ["an", "ans", "année", "années"])
+            .unit(Unit::Month, ["mois"])
+            .unit(Unit::Week, ["semaine", "semaines"])
+            .unit(Unit::Day, ["jour", "jours", "journée", "journées"])
+            .unit(Unit::Hour, ["heure", "heures"])
+            .unit(Unit::Minute, ["minute", "minutes"])
+            .unit(Unit::Second, ["seconde", "secondes"])
+            .word("quarter", ["trimestre", "trimestres"])
+            .word(
+                "business day",
+                [
+                    "jour ouvré",
+                    "jours ouvrés",
+                    "jour ouvrable",
+                    "jours ouvrables",
+                ],
+            )
+            .word("weekend", ["week-end", "weekend"])
+            .word("workweek", ["semaine de travail"])
+            .word("today", ["aujourd'hui"])
+            .word("tomorrow", ["demain"])
+            .word("overmorrow", ["après-demain"])
+            .word("yesterday", ["hier"])
+            .word("the day before yesterday", ["avant-hier"])
+            .word("now", ["maintenant"])
+            .word("new year's day", ["jour de l'an", "nouvel an"])
+            .word("new year's eve", ["saint-sylvestre"])
+            .word("christmas eve", ["veille de noël", "réveillon de noël"])
+            .word("christmas", ["noël"])
+            .word("good friday", ["vendredi saint"])
+            .word("easter", ["pâques"])
+            .word("easter monday", ["lundi de pâques"])
+            .word("ascension day", ["ascension"])
+            .word("pentecost", ["pentecôte"])
+            .word("whit monday", ["lundi de pentecôte"])
+            .word("this morning", ["ce matin"])
+            .word("this evening", ["ce soir"])
+            .word("morning", ["matin", "matinée"])
+            .word("noon", ["midi"])
+            .word("afternoon", ["après-midi"])
+            .word("evening", ["soir", "soirée"])
+            .word("night", ["nuit"])
+            .word("next", ["prochain", "prochaine", "suivant", "suivante"])
+            .word("last", ["dernier", "dernière", "passé", "passée"])
+            .word("this", ["ce", "cet", "cette"])
+            // "Il y a" goes before the duration, which the grammar reads from "before".
+            .word("before", ["il y a", "avant"])
+            .word("after", ["après"])
+            .word("at", ["à", "vers"])
+            .word("in", ["dans", "en"])
+            .word("and", ["et"])
+            .word("from", ["de", "depuis", "à partir de"])
+            .word("until", ["jusqu'à", "jusqu'au"])
+            .word("between", ["entre"])
+            .word("a", ["un", "une"])
+            .word("every", ["chaque", "tous les", "toutes les"])
+            .word("daily", ["quotidien", "quotidiennement"])
+            .word("weekly", ["hebdomadaire"])
+            .word("monthly", ["mensuel", "mensuellement"])
+            .word("quarterly", ["trimestriel"])
+            .word("yearly", ["annuel", "annuellement"])
+            .word("", ["le", "la", "les", "du"])
+    }
+}
@@ -0,0 +1,229 @@
+use std::ops::Range;
+
+use unicode_normalization::char::{decompose_canonical, is_combining_mark};
+
+/// The digit zero of every script whose digits are normalized to ASCII, like Arabic-Indic "٠" or
+/// Devanagari "०". The other nine digits follow each of them.
+const DIGIT_ZEROS: [u32; 19] = [
+    0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6, 0x0D66,
+    0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x17E0, 0x1810,
+];
+
+/// Input in the form the grammar expects, together with where each part of it came from in the
+/// original input.
+///
+/// Normalizing folds the case of the input, turns every kind of whitespace into a single space,
+/// maps full-width characters and digits of other scripts to ASCII and folds typographic
+/// punctuation like curly apostrophes and dashes into its plain form. Optionally, accents are
+/// stripped as well. See [`Parser::normalize`](crate::Parser::normalize).
+///
+/// # Examples
+///
+/// ```
+/// use human_date_parser::Parser;
+///
+/// let input = "\tNext\u{a0}Friday ＡＴ １７:００\n";
+/// let normalized = Parser::new().normalize(input);
+///
+/// assert_eq!(normalized.as_str(), "next friday at 17:00");
+/// assert_eq!(&input[normalized.original_range(5..11)], "Friday");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalized {
+    text: String,
+    /// The byte range in the original input that each byte of `text` comes from.
+    sources: Vec<Range<usize>>,
+}
+
+impl Normalized {
+    /// The normalized input.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Maps a byte offset in the normalized input back to the original input. Offsets at or past
+    /// the end map to the end of the last character that was kept.
+    pub fn original_offset(&self, offset: usize) -> usize {
+        match self.sources.get(offset) {
+            Some(source) => source.start,
+            None => self.sources.last().map_or(0, |source| source.end),
+        }
+    }
+
+    /// Maps a byte range in the normalized input back to the range of the original input it was
+    /// made from.
+    pub fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        let start = self.original_offset(range.start);
+        if range.end <= range.start {
+            return start..start;
+        }
+        let end = match self.sources.get(range.end - 1) {
+            Some(source) => source.end,
+            None => self.original_offset(range.end),
+        };
+        start..end
+    }
+
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Normalized {
+            text: String::with_capacity(capacity),
+            sources: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a character that comes from `source` in the original input.
+    pub(crate) fn push(&mut self, c: char, source: Range<usize>) {
+        self.text.push(c);
+        self.sources
+            .extend(std::iter::repeat(source).take(c.len_utf8()));
+    }
+
+    /// Appends text that replaces `source` in the original input, like a translated word.
+    pub(crate) fn push_str(&mut self, text: &str, source: Range<usize>) {
+        self.text.push_str(text);
+        self.sources
+            .extend(std::iter::repeat(source).take(text.len()));
+    }
+
+    /// Appends a byte range of `other` together with where it came from.
+    pub(crate) fn push_from(&mut self, other: &Normalized, range: Range<usize>) {
+        self.text.push_str(&other.text[range.clone()]);
+        self.sources.extend_from_slice(&other.sources[range]);
+    }
+
+    /// Strips the accents off every letter that is not wrapped in braces.
+    pub(crate) fn without_accents(&self) -> Normalized {
+        let mut stripped = Normalized::with_capacity(self.text.len());
+        let mut in_braces = false;
+        for (offset, c) in self.text.char_indices() {
+            match c {
+                '{' => in_braces = true,
+                '}' => in_braces = false,
+                _ => {}
+            }
+            if in_braces {
+                stripped.push(c, self.sources[offset].clone());
+            } else if !is_combining_mark(c) {
+                stripped.push(strip_accent(c), self.sources[offset].clone());
+            }
+        }
+        stripped
+    }
+}
+
+/// Normalizes `input`, see [`Normalized`]. Whitespace at the start and end is dropped.
+pub(crate) fn normalize(input: &str, strip_accents: bool) -> Normalized {
+    let mut normalized = Normalized::with_capacity(input.len());
+    normalize_chars(input, strip_accents, |c, source| normalized.push(c, source));
+    normalized
+}
+
+/// Normalizes `input` like [`normalize`], without keeping track of where the characters came from.
+pub(crate) fn normalize_str(input: &str, strip_accents: bool) -> String {
+    let mut normalized = String::with_capacity(input.len());
+    normalize_chars(input, strip_accents, |c, _| normalized.push(c));
+    normalized
+}
+
+/// Calls `push` with every normalized character and the byte range of the original input it comes
+/// from.
+fn normalize_chars(input: &str, strip_accents: bool, mut push: impl FnMut(char, Range<usize>)) {
+    let mut empty = true;
+    let mut space: Option<Range<usize>> = None;
+
+    for (start, c) in input.char_indices() {
+        let source = start..start + c.len_utf8();
+        if is_invisible(c) {
+            continue;
+        }
+        if c.is_whitespace() {
+            if !empty {
+                space.get_or_insert(source);
+            }
+            continue;
+        }
+
+        if let Some(space) = space.take() {
+            push(' ', space);
+        }
+        for c in fold_case(c) {
+            let c = fold_char(c);
+            match strip_accents {
+                // Accents that were already split off their letter, as in "e\u{301}".
+                true if is_combining_mark(c) => {}
+                true => push(strip_accent(c), source.clone()),
+                false => push(c, source.clone()),
+            }
+        }
+        empty = false;
+    }
+}
+
+/// Lowercases `c` the way Unicode case folding does, so that "Straße" and "STRASSE" both become
+/// "strasse". Letters are uppercased before they are lowercased, which turns ones like "ß" or the
+/// ligature "ﬀ" into the letters they are written as in capitals. This differs from Unicode's case
+/// folding in a few letters only, like the dotless "ı", which becomes "i".
+fn fold_case(c: char) -> impl Iterator<Item = char> {
+    c.to_uppercase().flat_map(char::to_lowercase)
+}
+
+/// Characters that take up no space and are dropped, like zero-width spaces and soft hyphens.
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}' | '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}'
+    )
+}
+
+/// Maps full-width characters, digits of other scripts and typographic punctuation to ASCII.
+fn fold_char(c: char) -> char {
+    if c.is_ascii() && c != '`' {
+        return c;
+    }
+
+    let code = u32::from(c);
+    if let Some(zero) = DIGIT_ZEROS
+        .iter()
+        .find(|&&zero| (zero..zero + 10).contains(&code))
+    {
+        return char::from_digit(code - zero, 10).unwrap_or(c);
+    }
+
+    match c {
+        // Full-width forms of the printable ASCII characters.
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(code - 0xFEE0).unwrap_or(c),
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' | '\u{02BC}'
+        | '\u{00B4}' | '`' => '\'',
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{2033}' => '"',
+        '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE63}' => '-',
+        '\u{2236}' => ':',
+        c => c,
+    }
+}
+
+/// Strips the accents off a lowercase letter, so "é" becomes "e" and "ő" becomes "o".
+///
+/// The letter is decomposed and only its base is kept, as long as everything else it decomposes
+/// into is a combining mark. That leaves letters like the Korean "한" alone. Letters whose stroke
+/// is part of the letter itself, like "ł", have no decomposition and are mapped by hand.
+pub(crate) fn strip_accent(c: char) -> char {
+    let mut base = None;
+    let mut only_marks = true;
+    decompose_canonical(c, |part| match base {
+        None => base = Some(part),
+        Some(_) => only_marks &= is_combining_mark(part),
+    });
+
+    match base {
+        Some(base) if only_marks && base != c => base,
+        _ => match c {
+            'ł' => 'l',
+            'đ' => 'd',
+            'ø' => 'o',
+            'ħ' => 'h',
+            'ŧ' => 't',
+            'ı' => 'i',
+            c => c,
+        },
+    }
+}
@@ -0,0 +1,520 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{NaiveDateTime, Weekday};
+
+use crate::anchor::Anchor;
+use crate::ast::{build_ast_from, build_recurrence_ast_from};
+use crate::business::{BasicCalendar, BusinessCalendar};
+use crate::extract::{extract, Extracted};
+use crate::granularity::{granularity_of, Granularity};
+use crate::holiday::HolidayCalendar;
+use crate::locale::{replace_words, Locale};
+use crate::normalize::{normalize, normalize_str, Normalized};
+use crate::recurrence::{parse_recurrence, Recurrence};
+use crate::suggest::{correction, suggestions, word_at};
+use crate::{parse_human_period, parse_human_time, ParseError, ParseRange, ParseResult};
+
+/// Settings that change how a [`Parser`] interprets its input.
+///
+/// Create them with [`ParseOptions::default`] and the builder methods of [`Parser`]. New options
+/// may be added in future versions without it being a breaking change.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// The first day of the week. It decides which days "next week Sunday" or the period "this
+    /// week" refer to, and how weeks are counted for recurrences like "every other Tuesday".
+    /// Defaults to [`Weekday::Mon`].
+    pub week_start: Weekday,
+    /// Whether references that leave out which week or year they mean, like "Friday",
+    /// "7 February" or "17:00", should be resolved into the future or the past. Defaults to
+    /// [`Preference::Current`].
+    pub prefer: Preference,
+    /// Which days "this Saturday", "next Saturday" and "last Saturday" refer to. Defaults to
+    /// [`WeekdayPolicy::NearestUpcoming`].
+    pub weekday_policy: WeekdayPolicy,
+    /// What happens when adding or subtracting years, quarters or months lands on a day that does
+    /// not exist, like "a month after January 31st". Defaults to [`MonthOverflow::Clamp`].
+    pub month_overflow: MonthOverflow,
+    /// The days that make up the weekend, used by "this weekend" and "next workweek". Defaults to
+    /// Saturday and Sunday.
+    pub weekend: Vec<Weekday>,
+    /// Which days count as business days for "in 3 business days" or "next working day". Defaults
+    /// to `None`, which treats every day outside of [`ParseOptions::weekend`] as a business day.
+    pub business_calendar: Option<Arc<dyn BusinessCalendar>>,
+    /// The holidays that can be referred to by name, as in "the day after Thanksgiving". Defaults
+    /// to [`HolidayCalendar::builtin`].
+    pub holidays: HolidayCalendar,
+    /// Custom dates that can be referred to by name, as in "2 days before sprint end". Names are
+    /// matched regardless of case and win over holidays with the same name. Defaults to none.
+    pub anchors: BTreeMap<String, Anchor>,
+    /// The language of the input. Defaults to [`Locale::english`].
+    pub locale: Locale,
+    /// Whether misspelled words like "tommorow" are replaced with the word they are closest to
+    /// when the input can not be parsed otherwise. Words are only replaced if one is closer than
+    /// all others and at most [`ParseOptions::max_typo_distance`] edits away. Defaults to `false`,
+    /// in which case the words are only suggested in the [`SyntaxError`](crate::SyntaxError).
+    pub autocorrect: bool,
+    /// How many characters may be inserted, removed, replaced or swapped for a word to count as a
+    /// misspelling of another. Shorter words allow fewer edits, one for every three characters.
+    /// Defaults to 2.
+    pub max_typo_distance: usize,
+    /// Whether accents are stripped from the input, so "Fête" matches an anchor named "fete".
+    /// Words of a [`Locale`] are always matched regardless of accents. Defaults to `false`.
+    pub strip_accents: bool,
+}
+
+impl ParseOptions {
+    /// Finds the anchor with the given name, regardless of case.
+    pub(crate) fn anchor(&self, name: &str) -> Option<&Anchor> {
+        let name = name.to_lowercase();
+        self.anchors
+            .iter()
+            .find(|(own, _)| own.to_lowercase() == name)
+            .map(|(_, anchor)| anchor)
+    }
+
+    /// The business calendar to use, falling back to one built from the weekend.
+    pub(crate) fn calendar(&self) -> Arc<dyn BusinessCalendar> {
+        match &self.business_calendar {
+            Some(calendar) => Arc::clone(calendar),
+            None => Arc::new(BasicCalendar::new(self.weekend.iter().copied())),
+        }
+    }
+}
+
+/// Business calendars are compared by identity, as they can not be compared by value.
+impl PartialEq for ParseOptions {
+    fn eq(&self, other: &Self) -> bool {
+        let same_calendar = match (&self.business_calendar, &other.business_calendar) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+
+        self.week_start == other.week_start
+            && self.prefer == other.prefer
+            && self.weekday_policy == other.weekday_policy
+            && self.month_overflow == other.month_overflow
+            && self.weekend == other.weekend
+            && same_calendar
+            && self.holidays == other.holidays
+            && self.anchors == other.anchors
+            && self.locale == other.locale
+            && self.strip_accents == other.strip_accents
+            && self.autocorrect == other.autocorrect
+            && self.max_typo_distance == other.max_typo_distance
+    }
+}
+
+impl Eq for ParseOptions {}
+
+/// Which way to resolve references that leave out part of the date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Preference {
+    /// "Friday" is the upcoming Friday, "7 February" lies in the current year and "17:00" is a
+    /// time without a date.
+    #[default]
+    Current,
+    /// "Friday" is the upcoming Friday, "7 February" is the next 7th of February, counting today,
+    /// and "17:00" is the next time it is 17:00, counting now.
+    Future,
+    /// "Friday" is the last Friday before today, "7 February" is the last 7th of February,
+    /// counting today, and "17:00" is the last time it was 17:00, counting now.
+    Past,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            week_start: Weekday::Mon,
+            prefer: Preference::Current,
+            weekday_policy: WeekdayPolicy::NearestUpcoming,
+            month_overflow: MonthOverflow::Clamp,
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+            business_calendar: None,
+            holidays: HolidayCalendar::builtin(),
+            anchors: BTreeMap::new(),
+            locale: Locale::english(),
+            autocorrect: false,
+            max_typo_distance: 2,
+            strip_accents: false,
+        }
+    }
+}
+
+/// How "this", "next" and "last" followed by a weekday are read. The examples assume today is
+/// Friday, January 1st.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WeekdayPolicy {
+    /// "Next" and "last" mean the nearest such weekday, not counting today. "This" means the
+    /// nearest upcoming one, counting today. "Next Saturday" and "this Saturday" are both January
+    /// 2nd, "next Friday" is January 8th and "last Friday" December 25th.
+    #[default]
+    NearestUpcoming,
+    /// The weekday in the next, current or last calendar week, as set by
+    /// [`ParseOptions::week_start`]. "Next Saturday" is January 9th, "this Monday" December 28th
+    /// and "last Thursday" December 24th.
+    CalendarWeek,
+    /// "Next" and "last" skip the nearest such weekday, counting today. "This" means the nearest
+    /// upcoming one, counting today. "Next Saturday" is January 9th, "next Friday" January 8th and
+    /// "last Thursday" December 24th.
+    SkipOne,
+}
+
+/// What to do when month arithmetic lands on a day the month does not have. The examples are for
+/// "in 1 month" on January 31st, 2010.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonthOverflow {
+    /// Use the last day of the month instead, February 28th.
+    #[default]
+    Clamp,
+    /// Carry the extra days over into the next month, March 3rd.
+    RollOver,
+    /// Fail with [`ProcessingError::InvalidDate`](crate::ProcessingError::InvalidDate).
+    Error,
+}
+
+/// Parses human-readable dates and times, according to its [`ParseOptions`].
+///
+/// [`from_human_time`](crate::from_human_time) and its siblings use a parser with the default
+/// options. Build your own one if you need to change any of them.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use human_date_parser::{ParseResult, Parser};
+///
+/// let parser = Parser::new();
+/// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     parser.parse("Tomorrow", now).unwrap(),
+///     ParseResult::Date(NaiveDate::from_ymd_opt(2010, 1, 2).unwrap())
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parser {
+    options: ParseOptions,
+}
+
+impl Parser {
+    /// Creates a parser with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a parser with the given options.
+    pub fn with_options(options: ParseOptions) -> Self {
+        Parser { options }
+    }
+
+    /// Sets the first day of the week. See [`ParseOptions::week_start`].
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.options.week_start = week_start;
+        self
+    }
+
+    /// Sets whether incomplete references are resolved into the future or the past. See
+    /// [`ParseOptions::prefer`].
+    pub fn prefer(mut self, prefer: Preference) -> Self {
+        self.options.prefer = prefer;
+        self
+    }
+
+    /// Sets which days "this", "next" and "last" followed by a weekday refer to. See
+    /// [`ParseOptions::weekday_policy`].
+    pub fn weekday_policy(mut self, weekday_policy: WeekdayPolicy) -> Self {
+        self.options.weekday_policy = weekday_policy;
+        self
+    }
+
+    /// Sets what happens when month arithmetic lands on a day that does not exist. See
+    /// [`ParseOptions::month_overflow`].
+    pub fn month_overflow(mut self, month_overflow: MonthOverflow) -> Self {
+        self.options.month_overflow = month_overflow;
+        self
+    }
+
+    /// Sets the days that make up the weekend, like Friday and Saturday. See
+    /// [`ParseOptions::weekend`].
+    pub fn weekend(mut self, weekend: impl IntoIterator<Item = Weekday>) -> Self {
+        self.options.weekend = weekend.into_iter().collect();
+        self
+    }
+
+    /// Sets which days count as business days, for example to take holidays into account. See
+    /// [`ParseOptions::business_calendar`].
+    pub fn business_calendar(mut self, calendar: impl BusinessCalendar + 'static) -> Self {
+        self.options.business_calendar = Some(Arc::new(calendar));
+        self
+    }
+
+    /// Sets the holidays that can be referred to by name. See [`ParseOptions::holidays`].
+    pub fn holidays(mut self, holidays: HolidayCalendar) -> Self {
+        self.options.holidays = holidays;
+        self
+    }
+
+    /// Registers a date under a name, like "payday", so it can be used anywhere "today" can.
+    /// Registering the same anchor under several names makes them aliases of each other. See
+    /// [`ParseOptions::anchors`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    /// use human_date_parser::{Anchor, ParseResult, Parser};
+    ///
+    /// let release = NaiveDate::from_ymd_opt(2010, 3, 1).unwrap();
+    /// let parser = Parser::new()
+    ///     .anchor("release day", release)
+    ///     .anchor("payday", Anchor::computed(|now| now.date().with_day(25).unwrap()));
+    /// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     parser.parse("2 days before release day at 10:00", now).unwrap(),
+    ///     ParseResult::DateTime(NaiveDate::from_ymd_opt(2010, 2, 27).unwrap().and_hms_opt(10, 0, 0).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     parser.parse("Payday", now).unwrap(),
+    ///     ParseResult::Date(NaiveDate::from_ymd_opt(2010, 1, 25).unwrap())
+    /// );
+    /// ```
+    pub fn anchor(mut self, name: impl Into<String>, anchor: impl Into<Anchor>) -> Self {
+        self.options
+            .anchors
+            .insert(name.into().to_lowercase(), anchor.into());
+        self
+    }
+
+    /// Sets the language of the input. See [`ParseOptions::locale`].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.options.locale = locale;
+        self
+    }
+
+    /// Sets whether accents are stripped from the input. See [`ParseOptions::strip_accents`].
+    pub fn strip_accents(mut self, strip_accents: bool) -> Self {
+        self.options.strip_accents = strip_accents;
+        self
+    }
+
+    /// Sets whether misspelled words are corrected. See [`ParseOptions::autocorrect`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use human_date_parser::{ParseError, Parser};
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    ///
+    /// let Err(ParseError::InvalidFormat(error)) = Parser::new().parse("Tommorow", now) else {
+    ///     panic!("\"Tommorow\" is misspelled");
+    /// };
+    /// assert_eq!(error.suggestions(), ["tomorrow"]);
+    ///
+    /// let parser = Parser::new().autocorrect(true);
+    /// assert_eq!(
+    ///     parser.parse("Tommorow", now).unwrap(),
+    ///     parser.parse("Tomorrow", now).unwrap()
+    /// );
+    /// ```
+    pub fn autocorrect(mut self, autocorrect: bool) -> Self {
+        self.options.autocorrect = autocorrect;
+        self
+    }
+
+    /// Sets how far off misspelled words may be. See [`ParseOptions::max_typo_distance`].
+    pub fn max_typo_distance(mut self, max_typo_distance: usize) -> Self {
+        self.options.max_typo_distance = max_typo_distance;
+        self
+    }
+
+    /// Returns the options this parser uses.
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// Parses a date, time or range. See [`from_human_time`](crate::from_human_time) for what
+    /// the result looks like.
+    pub fn parse(&self, input: &str, now: NaiveDateTime) -> Result<ParseResult, ParseError> {
+        let parsed = self.build(input, build_ast_from)?;
+
+        parse_human_time(parsed, now, &self.options)
+    }
+
+    /// Parses a date, time or range like [`Parser::parse`] and also returns how precise the input
+    /// was.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use human_date_parser::{Granularity, Parser};
+    ///
+    /// let parser = Parser::new();
+    /// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    ///
+    /// let (_, granularity) = parser.parse_with_granularity("Next month", now).unwrap();
+    /// assert_eq!(granularity, Granularity::Month);
+    ///
+    /// let (_, granularity) = parser.parse_with_granularity("Tomorrow 14:03:22", now).unwrap();
+    /// assert_eq!(granularity, Granularity::Second);
+    /// ```
+    pub fn parse_with_granularity(
+        &self,
+        input: &str,
+        now: NaiveDateTime,
+    ) -> Result<(ParseResult, Granularity), ParseError> {
+        let parsed = self.build(input, build_ast_from)?;
+        let granularity = granularity_of(&parsed);
+
+        Ok((parse_human_time(parsed, now, &self.options)?, granularity))
+    }
+
+    /// Parses the whole period an expression refers to. See
+    /// [`from_human_period`](crate::from_human_period) for what the result looks like.
+    pub fn parse_period(&self, input: &str, now: NaiveDateTime) -> Result<ParseRange, ParseError> {
+        let parsed = self.build(input, build_ast_from)?;
+
+        parse_human_period(parsed, now, &self.options)
+    }
+
+    /// Parses something that happens repeatedly. See
+    /// [`from_human_recurrence`](crate::from_human_recurrence) for what is supported.
+    pub fn parse_recurrence(
+        &self,
+        input: &str,
+        now: NaiveDateTime,
+    ) -> Result<Recurrence, ParseError> {
+        let parsed = self.build(input, build_recurrence_ast_from)?;
+
+        parse_recurrence(parsed, now, &self.options)
+    }
+
+    /// Finds every date, time, range and duration in a longer text, like a chat message or an
+    /// email. See [`extract_human_times`](crate::extract_human_times) for what the result looks
+    /// like.
+    ///
+    /// Expressions are read in the parser's locale, and spans refer to `text` as it was given.
+    /// Misspelled words are not corrected, since most words in a text are not meant as dates.
+    pub fn extract(&self, text: &str, now: NaiveDateTime) -> Vec<Extracted> {
+        extract(self, text, now)
+    }
+
+    /// Normalizes the input the way it is before parsing. See [`Normalized`] for what that
+    /// involves.
+    pub fn normalize(&self, input: &str) -> Normalized {
+        normalize(input, self.options.strip_accents)
+    }
+
+    /// Prepares the input and builds its syntax tree with `build`. Syntax errors point at the
+    /// original input and suggest corrections for a misspelled word. If
+    /// [`ParseOptions::autocorrect`] is set, the corrections are tried out, as are corrections for
+    /// a word only the start of which could be parsed, like "feb" in "Feburary 3".
+    fn build<T>(
+        &self,
+        input: &str,
+        build: impl Fn(&str) -> Result<T, ParseError> + Copy,
+    ) -> Result<T, ParseError> {
+        let prepared = self.prepare(input);
+        let error = match build(prepared.as_str()) {
+            Ok(ast) => return Ok(ast),
+            Err(ParseError::InvalidFormat(error)) => error.in_original(&prepared, input),
+            Err(error) => return Err(error),
+        };
+
+        let (suggestions, corrected) = self.correct(input, error.offset(), build);
+        match corrected {
+            Some(Ok(ast)) => Ok(ast),
+            _ => Err(ParseError::InvalidFormat(
+                error.with_suggestions(suggestions),
+            )),
+        }
+    }
+
+    /// Finds the words the word at `offset` in `input` might be a misspelling of. If
+    /// [`ParseOptions::autocorrect`] is set and one of them is closest, the word is replaced with
+    /// it and the result of building the corrected input is returned as well.
+    fn correct<T>(
+        &self,
+        input: &str,
+        offset: usize,
+        build: impl Fn(&str) -> Result<T, ParseError> + Copy,
+    ) -> (Vec<String>, Option<Result<T, ParseError>>) {
+        let range = word_at(input, offset);
+        let word = normalize_str(&input[range.clone()], true);
+        let vocabulary = self.vocabulary();
+        let suggestions = suggestions(
+            &word,
+            vocabulary.iter().map(String::as_str),
+            self.options.max_typo_distance,
+        );
+
+        let corrected = match correction(&word, &suggestions) {
+            Some(correction) if self.options.autocorrect => {
+                let corrected = format!(
+                    "{}{correction}{}",
+                    &input[..range.start],
+                    &input[range.end..]
+                );
+                Some(self.build(&corrected, build))
+            }
+            _ => None,
+        };
+
+        (suggestions, corrected)
+    }
+
+    /// The words of the locale and of the names of holidays and anchors, which misspelled words
+    /// are compared against besides the English words of the grammar.
+    fn vocabulary(&self) -> Vec<String> {
+        let holidays = self
+            .options
+            .holidays
+            .holidays()
+            .iter()
+            .flat_map(|h| h.names());
+        let anchors = self.options.anchors.keys().map(String::as_str);
+        let names = anchors
+            .chain(holidays)
+            .flat_map(str::split_whitespace)
+            .map(|word| normalize_str(word, true));
+
+        self.options
+            .locale
+            .words()
+            .map(str::to_string)
+            .chain(names)
+            .collect()
+    }
+
+    /// Normalizes the input, translates it into English and wraps the names of anchors and
+    /// holidays in braces, which is how the grammar recognizes them.
+    pub(crate) fn prepare(&self, input: &str) -> Normalized {
+        let holidays = self
+            .options
+            .holidays
+            .holidays()
+            .iter()
+            .flat_map(|h| h.names());
+        let anchors = self.options.anchors.keys().map(String::as_str);
+        let names: Vec<(String, String)> = anchors
+            .chain(holidays)
+            .map(|name| {
+                let normalized = normalize_str(name, self.options.strip_accents);
+                let marked = format!("{{{}}}", name.to_lowercase());
+                (normalized, marked)
+            })
+            .collect();
+
+        // Names are marked before translating, so they can contain words of the locale, and again
+        // afterwards for names the locale translates into English ones.
+        let marked = replace_words(&self.normalize(input), &names);
+        let translated = self.options.locale.translate(&marked);
+        replace_words(&translated, &names)
+    }
+}
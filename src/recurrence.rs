@@ -0,0 +1,396 @@
+use chrono::{
+    Datelike, Days, Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime, Weekday,
+};
+
+use crate::ast::{self, RecurrenceEnd, RecurrenceRule, TimeUnit};
+use crate::{
+    days_into_week, parse_human_time, parse_time, workdays, DefaultTime, InternalError, ParseError,
+    ParseOptions, ProcessingError,
+};
+
+mod export;
+
+use export::unit_name;
+pub use export::{CronError, RruleError};
+
+/// The Gregorian calendar repeats itself every 400 years, so a pattern that did not match within
+/// that many days (times its interval) will never match.
+const DAYS_IN_400_YEARS: u64 = 146_097;
+
+/// Something that happens repeatedly, like "every Monday at 9:00" or "on the 1st of each month".
+///
+/// Use [`Recurrence::occurrences_after`] to get the points in time it happens at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    /// The point in time the recurrence was parsed at. Every occurrence lies after it. Intervals
+    /// like "every other week" are counted from the first occurrence.
+    pub start: NaiveDateTime,
+    /// On which days or how often the recurrence happens.
+    pub pattern: RecurrencePattern,
+    /// The time of day occurrences happen at. If `None`, the time of day of `start` is used.
+    pub time: Option<NaiveTime>,
+    /// When the recurrence stops, if ever.
+    pub limit: Option<RecurrenceLimit>,
+    /// The first day of the week, which decides where the weeks of "every other Tuesday" begin.
+    pub week_start: Weekday,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecurrencePattern {
+    /// Every `interval` units of time, like "every 2 weeks" or "hourly".
+    Every { interval: u32, frequency: Frequency },
+    /// On the given days of every `interval`th week, like "every Monday and Friday".
+    Weekly {
+        interval: u32,
+        weekdays: Vec<Weekday>,
+    },
+    /// On a day of every `interval`th month, like "on the 15th of each month". Months that are too
+    /// short to have that day are skipped.
+    MonthlyOnDay { interval: u32, day: u32 },
+    /// On the nth weekday of every `interval`th month, like "every first Monday of the month". An
+    /// `ordinal` of `-1` means the last such weekday of the month.
+    MonthlyOnWeekday {
+        interval: u32,
+        ordinal: i32,
+        weekday: Weekday,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Yearly,
+    Monthly,
+    Weekly,
+    Daily,
+    Hourly,
+    Minutely,
+    Secondly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceLimit {
+    /// No occurrences after this point in time.
+    Until(NaiveDateTime),
+    /// Only this many occurrences, counted from the start of the recurrence.
+    Count(u32),
+}
+
+impl Recurrence {
+    /// Returns an iterator over every occurrence that lies after `now`, earliest first.
+    ///
+    /// The iterator ends once the limit of the recurrence is reached. Recurrences without one go
+    /// on until the end of the range of dates `chrono` supports.
+    pub fn occurrences_after(&self, now: NaiveDateTime) -> Occurrences<'_> {
+        Occurrences {
+            recurrence: self,
+            after: now,
+            previous: None,
+            anchor: self.start_anchor(),
+            count: 0,
+            finished: false,
+        }
+    }
+
+    /// The very first occurrence, regardless of the limit, or `None` for recurrences that never
+    /// happen.
+    fn first_occurrence(&self) -> Option<NaiveDateTime> {
+        self.next_occurrence(None, self.start_anchor())
+    }
+
+    /// Patterns like "every 2 weeks" count their intervals from the start, all others from their
+    /// first occurrence.
+    fn start_anchor(&self) -> Option<NaiveDate> {
+        match self.pattern {
+            RecurrencePattern::Every { .. } => Some(self.start.date()),
+            _ => None,
+        }
+    }
+
+    fn time_of_day(&self) -> NaiveTime {
+        self.time.unwrap_or(self.start.time())
+    }
+
+    /// Finds the first occurrence after `previous`, or the very first one if there is none.
+    ///
+    /// Intervals are counted from `anchor`. That is the start for patterns like "every 2 weeks" and
+    /// the date of the very first occurrence for ones like "every other Monday". Without an anchor
+    /// every matching day is accepted, which is how that very first occurrence is found.
+    fn next_occurrence(
+        &self,
+        previous: Option<NaiveDateTime>,
+        anchor: Option<NaiveDate>,
+    ) -> Option<NaiveDateTime> {
+        if let RecurrencePattern::Every {
+            interval,
+            frequency: frequency @ (Frequency::Hourly | Frequency::Minutely | Frequency::Secondly),
+        } = self.pattern
+        {
+            let step = match frequency {
+                Frequency::Hourly => ChronoDuration::hours(interval as i64),
+                Frequency::Minutely => ChronoDuration::minutes(interval as i64),
+                _ => ChronoDuration::seconds(interval as i64),
+            };
+            return previous.unwrap_or(self.start).checked_add_signed(step);
+        }
+
+        let time = self.time_of_day();
+        let previous = previous.unwrap_or(self.start);
+        let mut date = previous.date();
+        for _ in 0..DAYS_IN_400_YEARS.checked_mul(self.interval() as u64)? {
+            let candidate = date.and_time(time);
+            let in_interval = anchor.map_or(true, |anchor| self.in_interval(anchor, date));
+            if candidate > previous && self.matches(date) && in_interval {
+                return Some(candidate);
+            }
+            date = date.succ_opt()?;
+        }
+
+        None
+    }
+
+    fn interval(&self) -> u32 {
+        match self.pattern {
+            RecurrencePattern::Every { interval, .. }
+            | RecurrencePattern::Weekly { interval, .. }
+            | RecurrencePattern::MonthlyOnDay { interval, .. }
+            | RecurrencePattern::MonthlyOnWeekday { interval, .. } => interval,
+        }
+    }
+
+    /// Checks whether `date` is one of the days the recurrence happens on, ignoring its interval.
+    fn matches(&self, date: NaiveDate) -> bool {
+        let start = self.start.date();
+        match &self.pattern {
+            RecurrencePattern::Every { frequency, .. } => match frequency {
+                Frequency::Yearly => date.month() == start.month() && date.day() == start.day(),
+                Frequency::Monthly => date.day() == start.day(),
+                Frequency::Weekly => date.weekday() == start.weekday(),
+                _ => true,
+            },
+            RecurrencePattern::Weekly { weekdays, .. } => weekdays.contains(&date.weekday()),
+            RecurrencePattern::MonthlyOnDay { day, .. } => date.day() == *day,
+            RecurrencePattern::MonthlyOnWeekday {
+                ordinal, weekday, ..
+            } => {
+                let is_ordinal = if *ordinal < 0 {
+                    date.checked_add_days(Days::new(7))
+                        .map_or(true, |next| next.month() != date.month())
+                } else {
+                    (date.day0() / 7 + 1) as i32 == *ordinal
+                };
+                date.weekday() == *weekday && is_ordinal
+            }
+        }
+    }
+
+    /// Checks whether `date` lies a whole number of intervals after `anchor`.
+    fn in_interval(&self, anchor: NaiveDate, date: NaiveDate) -> bool {
+        let interval = self.interval();
+        let elapsed = match &self.pattern {
+            RecurrencePattern::Every { frequency, .. } => match frequency {
+                Frequency::Yearly => (date.year() - anchor.year()) as u32,
+                Frequency::Monthly => months_between(anchor, date),
+                Frequency::Weekly => ((date - anchor).num_days() / 7) as u32,
+                _ => (date - anchor).num_days() as u32,
+            },
+            RecurrencePattern::Weekly { .. } => {
+                let week_start = |date| start_of_week(date, self.week_start);
+                ((week_start(date) - week_start(anchor)).num_days() / 7) as u32
+            }
+            RecurrencePattern::MonthlyOnDay { .. } | RecurrencePattern::MonthlyOnWeekday { .. } => {
+                months_between(anchor, date)
+            }
+        };
+
+        elapsed % interval == 0
+    }
+}
+
+/// Iterator over the occurrences of a [`Recurrence`], created by
+/// [`Recurrence::occurrences_after`].
+#[derive(Debug, Clone)]
+pub struct Occurrences<'a> {
+    recurrence: &'a Recurrence,
+    after: NaiveDateTime,
+    previous: Option<NaiveDateTime>,
+    anchor: Option<NaiveDate>,
+    count: u32,
+    finished: bool,
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let next = self.recurrence.next_occurrence(self.previous, self.anchor);
+            let Some(occurrence) = next else {
+                self.finished = true;
+                break;
+            };
+            self.previous = Some(occurrence);
+            self.anchor.get_or_insert(occurrence.date());
+            self.count += 1;
+
+            self.finished = match self.recurrence.limit {
+                Some(RecurrenceLimit::Count(count)) => self.count > count,
+                Some(RecurrenceLimit::Until(until)) => occurrence > until,
+                None => false,
+            };
+
+            if !self.finished && occurrence > self.after {
+                return Some(occurrence);
+            }
+        }
+
+        None
+    }
+}
+
+pub(crate) fn parse_recurrence(
+    recurrence: ast::Recurrence,
+    now: NaiveDateTime,
+    options: &ParseOptions,
+) -> Result<Recurrence, ParseError> {
+    let pattern = parse_recurrence_rule(recurrence.rule, options)
+        .map_err(|err| ParseError::ProccessingErrors(vec![err]))?;
+
+    let time = recurrence
+        .time
+        .map(parse_time)
+        .transpose()
+        .map_err(|err| ParseError::ProccessingErrors(vec![err]))?;
+    if let (
+        Some(_),
+        RecurrencePattern::Every {
+            frequency: frequency @ (Frequency::Hourly | Frequency::Minutely | Frequency::Secondly),
+            ..
+        },
+    ) = (time, &pattern)
+    {
+        return Err(ParseError::ProccessingErrors(vec![
+            ProcessingError::RecurrenceTimeOfDay {
+                unit: format!("{frequency:?}").to_lowercase(),
+            },
+        ]));
+    }
+
+    let limit = match recurrence.end {
+        Some(RecurrenceEnd::Count(count)) => Some(RecurrenceLimit::Count(count)),
+        Some(RecurrenceEnd::Until(until)) => {
+            let until = parse_human_time(*until, now, options)?
+                .to_datetime(now, DefaultTime::EndOfDay)
+                .ok_or(InternalError::UnexpectedRange)?;
+            Some(RecurrenceLimit::Until(until))
+        }
+        None => None,
+    };
+
+    Ok(Recurrence {
+        start: now,
+        pattern,
+        time,
+        limit,
+        week_start: options.week_start,
+    })
+}
+
+fn parse_recurrence_rule(
+    rule: RecurrenceRule,
+    options: &ParseOptions,
+) -> Result<RecurrencePattern, ProcessingError> {
+    let pattern = match rule {
+        // Read like the workdays in a weekday list, so holidays are not taken into account.
+        RecurrenceRule::Interval(interval, TimeUnit::BusinessDay) => RecurrencePattern::Weekly {
+            interval,
+            weekdays: workdays(options),
+        },
+        RecurrenceRule::Interval(interval, time_unit) => {
+            let (interval, frequency) = match time_unit {
+                TimeUnit::Year => (interval, Frequency::Yearly),
+                TimeUnit::Quarter => {
+                    let months = interval.checked_mul(3).ok_or_else(|| {
+                        ProcessingError::IntervalTooLarge {
+                            interval,
+                            unit: "quarters".to_string(),
+                        }
+                    })?;
+                    (months, Frequency::Monthly)
+                }
+                TimeUnit::Month => (interval, Frequency::Monthly),
+                TimeUnit::Week => (interval, Frequency::Weekly),
+                TimeUnit::Day => (interval, Frequency::Daily),
+                TimeUnit::BusinessDay => unreachable!("Business days are handled above."),
+                TimeUnit::Hour => (interval, Frequency::Hourly),
+                TimeUnit::Minute => (interval, Frequency::Minutely),
+                TimeUnit::Second => (interval, Frequency::Secondly),
+            };
+            RecurrencePattern::Every {
+                interval,
+                frequency,
+            }
+        }
+        RecurrenceRule::Weekdays(interval, items) => {
+            let mut weekdays: Vec<Weekday> = items
+                .into_iter()
+                .flat_map(|item| match item {
+                    ast::WeekdayItem::Weekday(weekday) => vec![weekday.into()],
+                    ast::WeekdayItem::Workdays => workdays(options),
+                })
+                .collect();
+            weekdays.sort_by_key(|weekday| weekday.num_days_from_monday());
+            weekdays.dedup();
+            RecurrencePattern::Weekly { interval, weekdays }
+        }
+        RecurrenceRule::MonthDay(interval, day) => {
+            if !(1..=31).contains(&day) {
+                return Err(ProcessingError::InvalidDayOfMonth { day });
+            }
+            RecurrencePattern::MonthlyOnDay { interval, day }
+        }
+        RecurrenceRule::MonthWeekday(interval, ordinal, weekday) => {
+            RecurrencePattern::MonthlyOnWeekday {
+                interval,
+                ordinal,
+                weekday: weekday.into(),
+            }
+        }
+    };
+
+    let (interval, frequency) = match pattern {
+        RecurrencePattern::Every {
+            interval,
+            frequency,
+        } => (interval, frequency),
+        RecurrencePattern::Weekly { interval, .. } => (interval, Frequency::Weekly),
+        RecurrencePattern::MonthlyOnDay { interval, .. }
+        | RecurrencePattern::MonthlyOnWeekday { interval, .. } => (interval, Frequency::Monthly),
+    };
+    // The calendar repeats itself after 400 years, so longer intervals are of no use. They would
+    // only make looking for the next occurrence slow.
+    let max_interval = match frequency {
+        Frequency::Yearly => 400,
+        Frequency::Monthly => 400 * 12,
+        Frequency::Weekly => DAYS_IN_400_YEARS / 7,
+        Frequency::Daily => DAYS_IN_400_YEARS,
+        Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => u64::MAX,
+    };
+    if interval == 0 {
+        Err(ProcessingError::ZeroInterval)
+    } else if interval as u64 > max_interval {
+        Err(ProcessingError::IntervalTooLarge {
+            interval,
+            unit: unit_name(&frequency).to_string(),
+        })
+    } else {
+        Ok(pattern)
+    }
+}
+
+fn months_between(start: NaiveDate, end: NaiveDate) -> u32 {
+    ((end.year() - start.year()) * 12 + end.month0() as i32 - start.month0() as i32) as u32
+}
+
+fn start_of_week(date: NaiveDate, week_start: Weekday) -> NaiveDate {
+    date - ChronoDuration::days(days_into_week(date, week_start) as i64)
+}
@@ -0,0 +1,302 @@
+use chrono::{Datelike, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use thiserror::Error;
+
+use super::{Frequency, Recurrence, RecurrenceLimit, RecurrencePattern};
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CronError {
+    #[error("Cron expressions can not repeat more often than once a minute")]
+    BelowMinute,
+    #[error("Cron expressions can not express a time of day with seconds, like {time}")]
+    TimeWithSeconds { time: NaiveTime },
+    #[error("Cron expressions can not repeat every {interval} {}", unit_name(.frequency))]
+    UnsupportedInterval { interval: u32, frequency: Frequency },
+    #[error("Cron expressions can not express the nth weekday of a month")]
+    OrdinalWeekday,
+    #[error("Cron expressions can not stop after a number of occurrences or at a point in time")]
+    Limit,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RruleError {
+    #[error("iCalendar dates need a year from 0 to 9999, which {date} does not have")]
+    YearOutOfRange { date: NaiveDateTime },
+    #[error("The recurrence never happens, so there is no first occurrence to start from")]
+    NoOccurrences,
+}
+
+impl Recurrence {
+    /// Converts the recurrence into an iCalendar `DTSTART` and `RRULE` property, as described in
+    /// [RFC 5545](https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10), separated by a
+    /// line break.
+    ///
+    /// `DTSTART` is the first occurrence, which is where RFC 5545 counts intervals and `COUNT` from.
+    /// Expanding both therefore gives the same points in time as [`Recurrence::occurrences_after`].
+    /// Times are written as floating local times, as the recurrence has no time zone.
+    ///
+    /// Recurrences that never happen, and ones whose first occurrence or end lies after the year
+    /// 9999, which iCalendar can not write down, return an [`RruleError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use human_date_parser::from_human_recurrence;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let recurrence = from_human_recurrence("Every weekday at 8:30", now).unwrap();
+    ///
+    /// assert_eq!(
+    ///     recurrence.to_rrule().unwrap(),
+    ///     "DTSTART:20100104T083000\nRRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR;BYHOUR=8;BYMINUTE=30"
+    /// );
+    /// ```
+    pub fn to_rrule(&self) -> Result<String, RruleError> {
+        let first = self.first_occurrence().ok_or(RruleError::NoOccurrences)?;
+
+        let (frequency, interval) = match &self.pattern {
+            RecurrencePattern::Every {
+                interval,
+                frequency,
+            } => (*frequency, *interval),
+            RecurrencePattern::Weekly { interval, .. } => (Frequency::Weekly, *interval),
+            RecurrencePattern::MonthlyOnDay { interval, .. }
+            | RecurrencePattern::MonthlyOnWeekday { interval, .. } => {
+                (Frequency::Monthly, *interval)
+            }
+        };
+
+        let mut parts = vec![format!("FREQ={}", rrule_frequency(frequency))];
+        if interval != 1 {
+            parts.push(format!("INTERVAL={interval}"));
+        }
+
+        match &self.pattern {
+            RecurrencePattern::Every { .. } => {}
+            RecurrencePattern::Weekly { weekdays, .. } => {
+                let weekdays: Vec<&str> = weekdays.iter().map(|wd| rrule_weekday(*wd)).collect();
+                parts.push(format!("BYDAY={}", weekdays.join(",")));
+            }
+            RecurrencePattern::MonthlyOnDay { day, .. } => parts.push(format!("BYMONTHDAY={day}")),
+            RecurrencePattern::MonthlyOnWeekday {
+                ordinal, weekday, ..
+            } => parts.push(format!("BYDAY={ordinal}{}", rrule_weekday(*weekday))),
+        }
+
+        // Monday is the default in RFC 5545, so it is left out.
+        if self.week_start != Weekday::Mon {
+            parts.push(format!("WKST={}", rrule_weekday(self.week_start)));
+        }
+
+        let sub_daily = matches!(
+            frequency,
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly
+        );
+        if !sub_daily {
+            let time = self.time_of_day();
+            parts.push(format!("BYHOUR={}", time.hour()));
+            parts.push(format!("BYMINUTE={}", time.minute()));
+            if time.second() != 0 {
+                parts.push(format!("BYSECOND={}", time.second()));
+            }
+        }
+
+        match self.limit {
+            Some(RecurrenceLimit::Count(count)) => parts.push(format!("COUNT={count}")),
+            Some(RecurrenceLimit::Until(until)) => {
+                parts.push(format!("UNTIL={}", rrule_date_time(until)?))
+            }
+            None => {}
+        }
+
+        Ok(format!(
+            "DTSTART:{}\nRRULE:{}",
+            rrule_date_time(first)?,
+            parts.join(";")
+        ))
+    }
+
+    /// Converts the recurrence into a standard cron expression with five fields: minute, hour,
+    /// day of the month, month and day of the week.
+    ///
+    /// Cron has no notion of a start or an end, so only recurrences that line up with the clock
+    /// and the calendar can be converted. "Every 15 minutes" works, "every 7 minutes" does not, as
+    /// 60 is not divisible by 7. Neither do limits, intervals of days or weeks, and ordinal
+    /// weekdays like "the first Monday of the month". These return a [`CronError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use human_date_parser::from_human_recurrence;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let recurrence = from_human_recurrence("Every weekday at 8:30", now).unwrap();
+    ///
+    /// assert_eq!(recurrence.to_cron().unwrap(), "30 8 * * 1,2,3,4,5");
+    /// ```
+    pub fn to_cron(&self) -> Result<String, CronError> {
+        if self.limit.is_some() {
+            return Err(CronError::Limit);
+        }
+
+        let start = self.start;
+        let time = self.time_of_day();
+        let sub_daily = matches!(
+            self.pattern,
+            RecurrencePattern::Every {
+                frequency: Frequency::Hourly | Frequency::Minutely | Frequency::Secondly,
+                ..
+            }
+        );
+        if !sub_daily && time.second() != 0 {
+            return Err(CronError::TimeWithSeconds { time });
+        }
+
+        let (minute, hour) = (time.minute().to_string(), time.hour().to_string());
+        let fields = match &self.pattern {
+            RecurrencePattern::Every {
+                interval,
+                frequency,
+            } => {
+                let interval = *interval;
+                let unsupported = || CronError::UnsupportedInterval {
+                    interval,
+                    frequency: *frequency,
+                };
+                match frequency {
+                    Frequency::Secondly => return Err(CronError::BelowMinute),
+                    Frequency::Minutely => {
+                        if start.second() != 0 {
+                            return Err(CronError::TimeWithSeconds { time: start.time() });
+                        }
+                        let minutes =
+                            cron_steps(start.minute(), interval, 60, 0).ok_or_else(unsupported)?;
+                        [minutes, "*".into(), "*".into(), "*".into(), "*".into()]
+                    }
+                    Frequency::Hourly => {
+                        if start.second() != 0 {
+                            return Err(CronError::TimeWithSeconds { time: start.time() });
+                        }
+                        let hours =
+                            cron_steps(start.hour(), interval, 24, 0).ok_or_else(unsupported)?;
+                        [minute, hours, "*".into(), "*".into(), "*".into()]
+                    }
+                    Frequency::Daily if interval == 1 => {
+                        [minute, hour, "*".into(), "*".into(), "*".into()]
+                    }
+                    Frequency::Weekly if interval == 1 => {
+                        let weekday = start.weekday().num_days_from_sunday().to_string();
+                        [minute, hour, "*".into(), "*".into(), weekday]
+                    }
+                    Frequency::Monthly => {
+                        let months =
+                            cron_steps(start.month0(), interval, 12, 1).ok_or_else(unsupported)?;
+                        [minute, hour, start.day().to_string(), months, "*".into()]
+                    }
+                    Frequency::Yearly if interval == 1 => {
+                        let (day, month) = (start.day().to_string(), start.month().to_string());
+                        [minute, hour, day, month, "*".into()]
+                    }
+                    Frequency::Daily | Frequency::Weekly | Frequency::Yearly => {
+                        return Err(unsupported())
+                    }
+                }
+            }
+            RecurrencePattern::Weekly { interval, weekdays } => {
+                if *interval != 1 {
+                    return Err(CronError::UnsupportedInterval {
+                        interval: *interval,
+                        frequency: Frequency::Weekly,
+                    });
+                }
+                let mut weekdays: Vec<u32> = weekdays
+                    .iter()
+                    .map(|weekday| weekday.num_days_from_sunday())
+                    .collect();
+                weekdays.sort();
+                let weekdays: Vec<String> = weekdays.iter().map(u32::to_string).collect();
+                [minute, hour, "*".into(), "*".into(), weekdays.join(",")]
+            }
+            RecurrencePattern::MonthlyOnDay { interval, day } => {
+                // Months are counted from the first occurrence, which is not always in the
+                // month the recurrence starts in.
+                let first = self
+                    .occurrences_after(start)
+                    .next()
+                    .map_or(start.month0(), |first| first.month0());
+                let months =
+                    cron_steps(first, *interval, 12, 1).ok_or(CronError::UnsupportedInterval {
+                        interval: *interval,
+                        frequency: Frequency::Monthly,
+                    })?;
+                [minute, hour, day.to_string(), months, "*".into()]
+            }
+            RecurrencePattern::MonthlyOnWeekday { .. } => return Err(CronError::OrdinalWeekday),
+        };
+
+        Ok(fields.join(" "))
+    }
+}
+
+fn rrule_frequency(frequency: Frequency) -> &'static str {
+    match frequency {
+        Frequency::Yearly => "YEARLY",
+        Frequency::Monthly => "MONTHLY",
+        Frequency::Weekly => "WEEKLY",
+        Frequency::Daily => "DAILY",
+        Frequency::Hourly => "HOURLY",
+        Frequency::Minutely => "MINUTELY",
+        Frequency::Secondly => "SECONDLY",
+    }
+}
+
+fn rrule_date_time(date_time: NaiveDateTime) -> Result<String, RruleError> {
+    if !(0..=9999).contains(&date_time.year()) {
+        return Err(RruleError::YearOutOfRange { date: date_time });
+    }
+    Ok(date_time.format("%Y%m%dT%H%M%S").to_string())
+}
+
+pub(super) fn unit_name(frequency: &Frequency) -> &'static str {
+    match frequency {
+        Frequency::Yearly => "years",
+        Frequency::Monthly => "months",
+        Frequency::Weekly => "weeks",
+        Frequency::Daily => "days",
+        Frequency::Hourly => "hours",
+        Frequency::Minutely => "minutes",
+        Frequency::Secondly => "seconds",
+    }
+}
+
+fn rrule_weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+/// Lists every `interval`th value in a cycle of `cycle` values, starting at `first`. Only works
+/// if `interval` divides the cycle, as cron starts over at every cycle. `offset` is added to every
+/// value, to turn zero based months into the one based ones cron uses.
+fn cron_steps(first: u32, interval: u32, cycle: u32, offset: u32) -> Option<String> {
+    if cycle % interval != 0 {
+        return None;
+    }
+    if interval == 1 {
+        return Some("*".to_string());
+    }
+
+    let mut steps: Vec<u32> = (0..cycle / interval)
+        .map(|step| (first + step * interval) % cycle + offset)
+        .collect();
+    steps.sort();
+    let steps: Vec<String> = steps.iter().map(u32::to_string).collect();
+    Some(steps.join(","))
+}
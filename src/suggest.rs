@@ -0,0 +1,203 @@
+use std::ops::Range;
+
+/// How many suggestions are made at most.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The English words of the grammar, which misspelled words are compared against.
+const KEYWORDS: &[&str] = &[
+    "today",
+    "tomorrow",
+    "yesterday",
+    "overmorrow",
+    "now",
+    "this",
+    "next",
+    "last",
+    "upcoming",
+    "start",
+    "beginning",
+    "end",
+    "ago",
+    "in",
+    "at",
+    "on",
+    "of",
+    "the",
+    "from",
+    "to",
+    "until",
+    "till",
+    "between",
+    "and",
+    "before",
+    "after",
+    "every",
+    "each",
+    "other",
+    "times",
+    "first",
+    "third",
+    "fourth",
+    "fifth",
+    "daily",
+    "weekly",
+    "monthly",
+    "quarterly",
+    "yearly",
+    "annually",
+    "hourly",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+    "year",
+    "years",
+    "quarter",
+    "quarters",
+    "month",
+    "months",
+    "week",
+    "weeks",
+    "day",
+    "days",
+    "hour",
+    "hours",
+    "minute",
+    "minutes",
+    "second",
+    "seconds",
+    "business",
+    "working",
+    "workday",
+    "workdays",
+    "weekday",
+    "weekdays",
+    "weekend",
+    "workweek",
+    "morning",
+    "noon",
+    "midday",
+    "afternoon",
+    "evening",
+    "night",
+    "o'clock",
+];
+
+/// The words a misspelled word could have been meant as, closest first. A word may be at most
+/// `max_distance` edits away from a suggestion, and less the shorter it is, so that short words are
+/// not mistaken for one another. Words that are spelled right are not misspelled, so they have no
+/// suggestions.
+pub(crate) fn suggestions<'a>(
+    word: &str,
+    vocabulary: impl IntoIterator<Item = &'a str>,
+    max_distance: usize,
+) -> Vec<String> {
+    let max_distance = max_distance.min(word.chars().count() / 3);
+    let mut candidates: Vec<(usize, &str)> = KEYWORDS
+        .iter()
+        .copied()
+        .chain(vocabulary)
+        .map(|candidate| (edit_distance(word, candidate), candidate))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .collect();
+    if candidates.iter().any(|&(distance, _)| distance == 0) {
+        return Vec::new();
+    }
+    candidates.sort();
+    candidates.dedup();
+
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+/// The suggestion a misspelled word can be replaced with, if there is one that is closer than all
+/// others. Suggestions that only differ in a trailing "s", like "minute" and "minutes" for "minuts",
+/// are told apart by whether the word ends in "s" as well.
+pub(crate) fn correction(word: &str, suggestions: &[String]) -> Option<String> {
+    let distance = |suggestion: &String| edit_distance(word, suggestion);
+    let best = distance(suggestions.first()?);
+    let closest: Vec<&String> = suggestions
+        .iter()
+        .filter(|suggestion| distance(suggestion) == best)
+        .collect();
+
+    let correction = match closest[..] {
+        [only] => only,
+        _ => {
+            let plural = word.ends_with('s');
+            let same_ending: Vec<&String> = closest
+                .into_iter()
+                .filter(|suggestion| suggestion.ends_with('s') == plural)
+                .collect();
+            let [only] = same_ending[..] else {
+                return None;
+            };
+            only
+        }
+    };
+
+    (correction != word).then(|| correction.clone())
+}
+
+/// The byte range of the word around `offset`.
+pub(crate) fn word_at(input: &str, offset: usize) -> Range<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '\'';
+    let start = input[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|&(_, c)| is_word(c))
+        .last()
+        .map_or(offset, |(start, _)| start);
+    let end = input[offset..]
+        .find(|c: char| !is_word(c))
+        .map_or(input.len(), |end| offset + end);
+
+    start..end
+}
+
+/// How many characters have to be inserted, removed, replaced or swapped with their neighbour to
+/// turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // The distances between the first i characters of `a` and the first j characters of `b`.
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    distances[0] = (0..=b.len()).collect();
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
@@ -0,0 +1,236 @@
+use std::fmt::Display;
+use std::ops::Range;
+
+use pest::error::{Error, ErrorVariant, InputLocation};
+
+use crate::ast::Rule;
+use crate::normalize::Normalized;
+
+/// Where input stopped making sense and what could have been there instead, as returned by
+/// [`ParseError::InvalidFormat`](crate::ParseError::InvalidFormat).
+///
+/// Positions refer to the input as it was given, before it was normalized or translated. The
+/// `Display` implementation points at the problem with a caret:
+///
+/// ```text
+/// Could not match input to any known format at line 1, column 6: expected a weekday, [...] found "blah"
+///   |
+/// 1 | Next blah
+///   |      ^^^^
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use human_date_parser::{ParseError, Parser};
+///
+/// let now = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let Err(ParseError::InvalidFormat(error)) = Parser::new().parse("Next blah", now) else {
+///     panic!("\"Next blah\" is not a date");
+/// };
+///
+/// assert_eq!(error.offset(), 5);
+/// assert_eq!((error.line(), error.column()), (1, 6));
+/// assert_eq!(error.found(), Some("blah"));
+/// assert!(error.expected().iter().any(|expected| expected == "a weekday"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    input: String,
+    span: Range<usize>,
+    expected: Vec<String>,
+    suggestions: Vec<String>,
+}
+
+impl SyntaxError {
+    pub(crate) fn from_pest(input: &str, error: Error<Rule>) -> Self {
+        let offset = match error.location {
+            InputLocation::Pos(offset) => offset,
+            InputLocation::Span((start, _)) => start,
+        };
+        let expected = match error.variant {
+            ErrorVariant::ParsingError { positives, .. } => {
+                let mut expected: Vec<String> = positives
+                    .into_iter()
+                    .filter_map(describe)
+                    .map(str::to_string)
+                    .collect();
+                expected.sort();
+                expected.dedup();
+                expected
+            }
+            ErrorVariant::CustomError { .. } => Vec::new(),
+        };
+        // Errors in the middle of a word, like after "min" in "minuts", cover the whole word.
+        let start = input[..offset]
+            .rfind(|c: char| !c.is_alphanumeric())
+            .map_or(0, |start| start + 1);
+        let start = if input[offset..].starts_with(char::is_alphanumeric) {
+            start
+        } else {
+            offset
+        };
+        let end = input[offset..]
+            .find(' ')
+            .map_or(input.len(), |end| offset + end);
+
+        SyntaxError {
+            input: input.to_string(),
+            span: start..end,
+            expected,
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Moves the error from the prepared input it was found in to the original `input`.
+    pub(crate) fn in_original(self, prepared: &Normalized, input: &str) -> Self {
+        SyntaxError {
+            input: input.to_string(),
+            span: prepared.original_range(self.span),
+            ..self
+        }
+    }
+
+    pub(crate) fn with_suggestions(self, suggestions: Vec<String>) -> Self {
+        SyntaxError {
+            suggestions,
+            ..self
+        }
+    }
+
+    /// The input that could not be parsed.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The byte range of the input that could not be parsed, usually a single word. It is empty
+    /// if the input ended too early.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The byte offset into the input at which parsing failed.
+    pub fn offset(&self) -> usize {
+        self.span.start
+    }
+
+    /// The line parsing failed on, counting from 1.
+    pub fn line(&self) -> usize {
+        self.input[..self.offset()].matches('\n').count() + 1
+    }
+
+    /// The column parsing failed at, counting characters from 1.
+    pub fn column(&self) -> usize {
+        self.before_on_line().chars().count() + 1
+    }
+
+    /// The text that could not be parsed, or `None` if the input ended too early.
+    pub fn found(&self) -> Option<&str> {
+        let found = &self.input[self.span.clone()];
+        (!found.is_empty()).then_some(found)
+    }
+
+    /// Descriptions of what could have come instead of [`SyntaxError::found`], like "a weekday" or
+    /// "a time".
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+
+    /// Words that might have been meant instead of a misspelled one, closest first, like
+    /// "tomorrow" for "tommorow".
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    /// The part of the line parsing failed on that comes before the failure.
+    fn before_on_line(&self) -> &str {
+        let before = &self.input[..self.offset()];
+        before.rsplit('\n').next().unwrap_or(before)
+    }
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Could not match input to any known format at line {}, column {}: ",
+            self.line(),
+            self.column()
+        )?;
+        if let Some((last, rest)) = self.expected.split_last() {
+            write!(f, "expected ")?;
+            if !rest.is_empty() {
+                write!(f, "{} or ", rest.join(", "))?;
+            }
+            write!(f, "{last}, ")?;
+        }
+        match self.found() {
+            Some(found) => writeln!(f, "found \"{found}\"")?,
+            None => writeln!(f, "found the end of the input")?,
+        }
+
+        let line_number = self.line().to_string();
+        let gutter = " ".repeat(line_number.len());
+        let before = self.before_on_line();
+        let line_start = self.offset() - before.len();
+        let line = self.input[line_start..].lines().next().unwrap_or_default();
+        // Tabs are kept in the padding, so the caret lines up with the text above it.
+        let padding: String = before
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = self
+            .found()
+            .and_then(|found| found.lines().next())
+            .map_or(1, |found| found.chars().count().max(1));
+
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line_number} | {line}")?;
+        write!(f, "{gutter} | {padding}{}", "^".repeat(width))?;
+
+        if let Some((last, rest)) = self.suggestions.split_last() {
+            write!(f, "\n{gutter} = help: did you mean ")?;
+            if !rest.is_empty() {
+                let rest: Vec<String> = rest.iter().map(|s| format!("\"{s}\"")).collect();
+                write!(f, "{} or ", rest.join(", "))?;
+            }
+            write!(f, "\"{last}\"?")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Describes what a rule of the grammar stands for, or returns `None` for rules that are only
+/// there to structure the grammar.
+fn describe(rule: Rule) -> Option<&'static str> {
+    Some(match rule {
+        Rule::HumanTime | Rule::Range | Rule::SingleTime | Rule::DateTime => "a date or time",
+        Rule::Num | Rule::BareHour | Rule::Quantifier | Rule::Duration => "a number",
+        Rule::YearNum => "a year",
+        Rule::Time => "a time",
+        Rule::Date | Rule::IsoDate | Rule::DottedDate => "a date",
+        Rule::Weekday | Rule::WeekdayItem | Rule::WeekdayList => "a weekday",
+        Rule::Month_Name => "a month",
+        Rule::TimeUnit | Rule::Week => "a unit of time",
+        Rule::RelativeSpecifier => "\"this\", \"next\" or \"last\"",
+        Rule::OffsetDirection => "\"before\" or \"after\"",
+        Rule::PartOfDay => "a part of the day",
+        Rule::Named => "a holiday",
+        Rule::StartOf => "\"start of\"",
+        Rule::EndOf => "\"end of\"",
+        Rule::Weekend => "\"weekend\"",
+        Rule::Workweek => "\"workweek\"",
+        Rule::Today => "\"today\"",
+        Rule::Tomorrow => "\"tomorrow\"",
+        Rule::Yesterday => "\"yesterday\"",
+        Rule::Now => "\"now\"",
+        Rule::In => "\"in\"",
+        Rule::QuarterOfYear => "a quarter",
+        Rule::QuarterNum => "a quarter from 1 to 4",
+        Rule::RecurrenceRule => "a recurrence",
+        Rule::EOI => "the end of the input",
+        _ => return None,
+    })
+}
@@ -1,7 +1,8 @@
-#[allow(non_snake_case)]
+#![allow(non_snake_case)]
 use super::*;
 use crate::ast::DateTimeParser;
 use crate::ast::Rule;
+use chrono::Timelike;
 use pest_consume::Parser;
 
 /// Generates the test cases to remove a bunch of boilerplate code for the test setup.
@@ -11,8 +12,8 @@ macro_rules! generate_test_cases {
                 concat_idents::concat_idents!(ast_fn = build_ast_, $case {
                     #[test]
                     fn ast_fn () {
-                        let input = $case.to_lowercase();
-                        let result = DateTimeParser::parse(Rule::HumanTime, &input)
+                        let input = crate::Parser::new().prepare($case);
+                        let result = DateTimeParser::parse(Rule::HumanTime, input.as_str())
                             .and_then(|result| result.single())
                             .unwrap();
 
@@ -28,11 +29,8 @@ macro_rules! generate_test_cases {
                         let result = from_human_time(&input, now).unwrap();
                         let expected = NaiveDateTime::parse_from_str( $expected , "%Y-%m-%d %H:%M:%S").unwrap();
 
-                        let result = match result {
-                            ParseResult::DateTime(datetime) => datetime,
-                            ParseResult::Date(date) => NaiveDateTime::new(date, now.time()),
-                            ParseResult::Time(time) => NaiveDateTime::new(now.date(), time),
-                        };
+                        let result = result.to_datetime(now, DefaultTime::Now)
+                            .unwrap_or_else(|| panic!("Expected a single point in time, got {result}"));
 
                         println!("Result: {result}\nExpected: {expected}\nNote: Maximum difference between these values allowed is 10ms.");
                         assert!((result - expected).abs() < chrono::Duration::milliseconds(10));
@@ -42,6 +40,277 @@ macro_rules! generate_test_cases {
         };
     }
 
+/// Variant of above for inputs that describe a range. Both bounds are compared like above.
+macro_rules! generate_range_test_cases {
+        ( $( $case:literal = ($start:literal, $end:literal) ),* ) => {
+            $(
+                concat_idents::concat_idents!(fn_name = parse_range_, $case {
+                    #[test]
+                    fn fn_name () {
+                        let input = $case.to_lowercase();
+                        let now = NaiveDateTime::new(NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(), NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+                        let result = from_human_time(&input, now).unwrap();
+                        let expected_start = NaiveDateTime::parse_from_str( $start , "%Y-%m-%d %H:%M:%S").unwrap();
+                        let expected_end = NaiveDateTime::parse_from_str( $end , "%Y-%m-%d %H:%M:%S").unwrap();
+
+                        let (start, end) = match result {
+                            ParseResult::Range(range) => range_bounds(range, now),
+                            other => panic!("Expected a range, got {other}"),
+                        };
+
+                        println!("Result: {start} - {end}\nExpected: {expected_start} - {expected_end}");
+                        assert_eq!(start, expected_start);
+                        assert_eq!(end, expected_end);
+                    }
+                });
+            )*
+        };
+    }
+
+/// Variant of above for the period API, which always returns a range.
+macro_rules! generate_period_test_cases {
+        ( $( $case:literal = ($start:literal, $end:literal) ),* ) => {
+            $(
+                concat_idents::concat_idents!(fn_name = parse_period_, $case {
+                    #[test]
+                    fn fn_name () {
+                        let input = $case.to_lowercase();
+                        let now = NaiveDateTime::new(NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(), NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+                        let result = from_human_period(&input, now).unwrap();
+                        let expected_start = NaiveDateTime::parse_from_str( $start , "%Y-%m-%d %H:%M:%S").unwrap();
+                        let expected_end = NaiveDateTime::parse_from_str( $end , "%Y-%m-%d %H:%M:%S").unwrap();
+
+                        let (start, end) = range_bounds(result, now);
+
+                        println!("Result: {start} - {end}\nExpected: {expected_start} - {expected_end}");
+                        assert_eq!(start, expected_start);
+                        assert_eq!(end, expected_end);
+                    }
+                });
+            )*
+        };
+    }
+
+/// Turns both bounds of a range into `NaiveDateTime`s the same way single results are.
+fn range_bounds(range: ParseRange, now: NaiveDateTime) -> (NaiveDateTime, NaiveDateTime) {
+    match range {
+        ParseRange::DateTime(start, end) => (start, end),
+        ParseRange::Date(start, end) => (
+            NaiveDateTime::new(start, now.time()),
+            NaiveDateTime::new(end, now.time()),
+        ),
+        ParseRange::Time(start, end) => (
+            NaiveDateTime::new(now.date(), start),
+            NaiveDateTime::new(now.date(), end),
+        ),
+    }
+}
+
+/// Variant of above for recurrences. Compares the first occurrences after `now` with the expected
+/// ones and checks that the recurrence ends after them if it has a limit.
+macro_rules! generate_recurrence_test_cases {
+        ( $( $case:literal = [ $( $expected:literal ),* ] $( , $end:ident )? );* ) => {
+            $(
+                concat_idents::concat_idents!(fn_name = parse_recurrence_, $case {
+                    #[test]
+                    fn fn_name () {
+                        let input = $case.to_lowercase();
+                        let now = NaiveDateTime::new(NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(), NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+                        let recurrence = from_human_recurrence(&input, now).unwrap();
+                        let expected: Vec<NaiveDateTime> = vec![ $( NaiveDateTime::parse_from_str( $expected , "%Y-%m-%d %H:%M:%S").unwrap() ),* ];
+
+                        let mut occurrences = recurrence.occurrences_after(now);
+                        let result: Vec<NaiveDateTime> = occurrences.by_ref().take(expected.len()).collect();
+
+                        println!("Result: {result:?}\nExpected: {expected:?}");
+                        assert_eq!(result, expected);
+                        $( assert_eq!(occurrences.next(), None, stringify!($end)); )?
+                    }
+                });
+            )*
+        };
+    }
+
+/// Variant of above for converting recurrences into RRULE and cron expressions. A cron expression
+/// of `None` means the conversion is expected to fail.
+macro_rules! generate_recurrence_export_test_cases {
+        ( $( $case:literal = ($rrule:literal, $cron:expr) ),* ) => {
+            $(
+                concat_idents::concat_idents!(fn_name = export_recurrence_, $case {
+                    #[test]
+                    fn fn_name () {
+                        let input = $case.to_lowercase();
+                        let now = NaiveDateTime::new(NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(), NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+                        let recurrence = from_human_recurrence(&input, now).unwrap();
+                        let expected_cron: Option<&str> = $cron;
+
+                        let rrule = recurrence.to_rrule().unwrap();
+                        let cron = recurrence.to_cron();
+
+                        println!("Result: {rrule} / {cron:?}\nExpected: {} / {expected_cron:?}", $rrule);
+                        assert_eq!(rrule, $rrule);
+                        assert_eq!(cron.ok().as_deref(), expected_cron);
+                    }
+                });
+            )*
+        };
+    }
+
+/// Variant of above for formatting. Checks the phrase for the given point in time and that parsing
+/// it leads back to the same point in time.
+macro_rules! generate_format_test_cases {
+        ( $( $case:literal = $expected:literal ),* ) => {
+            $(
+                concat_idents::concat_idents!(fn_name = format_, $case {
+                    #[test]
+                    fn fn_name () {
+                        let now = NaiveDateTime::new(NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(), NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+                        let target = NaiveDateTime::parse_from_str( $case , "%Y-%m-%d %H:%M:%S").unwrap();
+
+                        let result = to_human_time(target, now, FormatOptions::default());
+
+                        println!("Result: {result}\nExpected: {}", $expected);
+                        assert_eq!(result, $expected);
+                        assert_eq!(round_trip(target, now, FormatOptions::default(), &crate::Parser::new()), target);
+                    }
+                });
+            )*
+        };
+    }
+
+/// The time most tests are run at, Friday, January 1st 2010 at noon.
+fn now() -> NaiveDateTime {
+    NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+    )
+}
+
+/// Formats `target` and parses the result again with `parser`.
+fn round_trip(
+    target: NaiveDateTime,
+    now: NaiveDateTime,
+    options: FormatOptions,
+    parser: &crate::Parser,
+) -> NaiveDateTime {
+    let phrase = to_human_time(target, now, options);
+    let result = parser.parse(&phrase, now);
+    result
+        .as_ref()
+        .ok()
+        .and_then(|result| result.to_datetime(now, DefaultTime::Now))
+        .unwrap_or_else(|| panic!("Could not parse \"{phrase}\" back: {result:?}"))
+}
+
+/// Expands the output of `Recurrence::to_rrule` into its first `limit` points in time. Written
+/// after RFC 5545 rather than the recurrence code, and only supports the parts `to_rrule` uses.
+fn expand_rrule(rrule: &str, limit: usize) -> Vec<NaiveDateTime> {
+    let parse_date_time = |s: &str| NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S").unwrap();
+    let parse_weekday = |s: &str| match s {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        _ => Weekday::Sun,
+    };
+
+    let (dtstart, rule) = rrule.split_once('\n').unwrap();
+    let dtstart = parse_date_time(dtstart.strip_prefix("DTSTART:").unwrap());
+    let parts: std::collections::HashMap<&str, &str> = rule
+        .strip_prefix("RRULE:")
+        .unwrap()
+        .split(';')
+        .map(|part| part.split_once('=').unwrap())
+        .collect();
+    let number = |key: &str| parts.get(key).map(|value| value.parse::<i64>().unwrap());
+    let interval = number("INTERVAL").unwrap_or(1);
+    let count = number("COUNT").map(|count| count as usize);
+    let until = parts.get("UNTIL").map(|until| parse_date_time(until));
+    let week_start = parts
+        .get("WKST")
+        .map_or(Weekday::Mon, |wkst| parse_weekday(wkst));
+    let time = NaiveTime::from_hms_opt(
+        number("BYHOUR").map_or(dtstart.hour(), |hour| hour as u32),
+        number("BYMINUTE").map_or(dtstart.minute(), |minute| minute as u32),
+        number("BYSECOND").map_or(dtstart.second(), |second| second as u32),
+    )
+    .unwrap();
+    let by_day: Vec<(i32, Weekday)> = parts.get("BYDAY").map_or(vec![], |days| {
+        days.split(',')
+            .map(|day| {
+                let (ordinal, weekday) = day.split_at(day.len() - 2);
+                (ordinal.parse().unwrap_or(0), parse_weekday(weekday))
+            })
+            .collect()
+    });
+
+    // Every period of the frequency, like a month for FREQ=MONTHLY, gives a set of candidates.
+    let candidates = |period: i64| -> Vec<NaiveDateTime> {
+        let step = period * interval;
+        let date = dtstart.date();
+        let in_month = |months: i64| -> Vec<NaiveDate> {
+            let month0 = date.year() as i64 * 12 + date.month0() as i64 + months;
+            let (year, month) = ((month0 / 12) as i32, (month0 % 12) as u32 + 1);
+            let days: Vec<NaiveDate> = (1..=31)
+                .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+                .collect();
+            if let Some(day) = number("BYMONTHDAY") {
+                days.into_iter().filter(|d| d.day() as i64 == day).collect()
+            } else if let Some(&(ordinal, weekday)) = by_day.first() {
+                let matching: Vec<NaiveDate> = days
+                    .into_iter()
+                    .filter(|d| d.weekday() == weekday)
+                    .collect();
+                let index = if ordinal < 0 {
+                    matching.len() as i32 + ordinal
+                } else {
+                    ordinal - 1
+                };
+                matching.get(index as usize).copied().into_iter().collect()
+            } else {
+                days.into_iter().filter(|d| d.day() == date.day()).collect()
+            }
+        };
+
+        match parts["FREQ"] {
+            "SECONDLY" => vec![dtstart + chrono::Duration::seconds(step)],
+            "MINUTELY" => vec![dtstart + chrono::Duration::minutes(step)],
+            "HOURLY" => vec![dtstart + chrono::Duration::hours(step)],
+            freq => {
+                let dates = match freq {
+                    "DAILY" => vec![date + chrono::Duration::days(step)],
+                    "WEEKLY" => {
+                        let offset = (7 + date.weekday().num_days_from_monday()
+                            - week_start.num_days_from_monday())
+                            % 7;
+                        let first = date - chrono::Duration::days(offset as i64)
+                            + chrono::Duration::weeks(step);
+                        (0..7)
+                            .map(|day| first + chrono::Duration::days(day))
+                            .filter(|d| {
+                                by_day.is_empty() && d.weekday() == date.weekday()
+                                    || by_day.iter().any(|(_, weekday)| d.weekday() == *weekday)
+                            })
+                            .collect()
+                    }
+                    "MONTHLY" => in_month(step),
+                    _ => in_month(step * 12),
+                };
+                dates.into_iter().map(|date| date.and_time(time)).collect()
+            }
+        }
+    };
+
+    (0..)
+        .flat_map(candidates)
+        .filter(|occurrence| *occurrence >= dtstart)
+        .take_while(|occurrence| until.map_or(true, |until| *occurrence <= until))
+        .take(count.unwrap_or(limit).min(limit))
+        .collect()
+}
+
 /// Variant of aboce to check if parsing fails gracefully
 macro_rules! generate_test_cases_error {
         ( $( $case:literal ),* ) => {
@@ -79,25 +348,26 @@ generate_test_cases!(
     "This week" = "2010-01-01 00:00:00",
     "Last week" = "2009-12-25 00:00:00",
     "Next week Monday" = "2010-01-04 00:00:00",
+    "Next week Sunday" = "2010-01-10 00:00:00",
     "This week Friday" = "2010-01-01 00:00:00",
     "This week Monday" = "2009-12-28 00:00:00",
     "Last week Tuesday" = "2009-12-22 00:00:00",
     "Last Monday" = "2009-12-28 00:00:00",
-    "Last Tueday" = "2009-12-29 00:00:00",
+    "Last Tuesday" = "2009-12-29 00:00:00",
     "Last Wednesday" = "2009-12-30 00:00:00",
     "Last Thursday" = "2009-12-31 00:00:00",
     "Last Friday" = "2009-12-25 00:00:00",
     "Last Saturday" = "2009-12-26 00:00:00",
     "Last Sunday" = "2009-12-27 00:00:00",
     "This Monday" = "2010-01-04 00:00:00",
-    "This Tueday" = "2010-01-05 00:00:00",
+    "This Tuesday" = "2010-01-05 00:00:00",
     "This Wednesday" = "2010-01-06 00:00:00",
     "This Thursday" = "2010-01-07 00:00:00",
     "This Friday" = "2010-01-01 00:00:00",
     "This Saturday" = "2010-01-02 00:00:00",
     "This Sunday" = "2010-01-03 00:00:00",
     "Next Monday" = "2010-01-04 00:00:00",
-    "Next Tueday" = "2010-01-05 00:00:00",
+    "Next Tuesday" = "2010-01-05 00:00:00",
     "Next Wednesday" = "2010-01-06 00:00:00",
     "Next Thursday" = "2010-01-07 00:00:00",
     "Next Friday" = "2010-01-08 00:00:00",
@@ -129,7 +399,2170 @@ generate_test_cases!(
     "12 hours ago at 04:00" = "2009-12-31 16:00:00",
     "12 hours ago at today" = "2009-12-31 12:00:00",
     "12 hours ago at 7 days ago" = "2009-12-24 12:00:00",
-    "7 days ago at 7 days ago" = "2009-12-18 00:00:00"
+    "7 days ago at 7 days ago" = "2009-12-18 00:00:00",
+    "Q2" = "2010-04-01 00:00:00",
+    "Q4 2025" = "2025-10-01 00:00:00",
+    "Next quarter" = "2010-04-01 00:00:00",
+    "In 2 quarters" = "2010-07-01 00:00:00",
+    "A quarter ago" = "2009-10-01 00:00:00",
+    "2025" = "2025-01-01 00:00:00",
+    "In 2025" = "2025-01-01 00:00:00",
+    "March 2025" = "2025-03-01 00:00:00",
+    "March" = "2010-03-01 00:00:00",
+    "In March" = "2010-03-01 00:00:00",
+    "This March" = "2010-03-01 00:00:00",
+    "Next March" = "2010-03-01 00:00:00",
+    "Last March" = "2009-03-01 00:00:00",
+    "Last December" = "2009-12-01 00:00:00",
+    "Next January" = "2011-01-01 00:00:00",
+    "In May" = "2010-05-01 00:00:00",
+    "2000 days ago" = "2004-07-11 00:00:00",
+    "In 1000 years" = "3010-01-01 00:00:00",
+    "Tomorrow morning" = "2010-01-02 09:00:00",
+    "Yesterday evening" = "2009-12-31 18:00:00",
+    "Today noon" = "2010-01-01 12:00:00",
+    "This afternoon" = "2010-01-01 15:00:00",
+    "Night" = "2010-01-01 21:00:00",
+    "5 o'clock" = "2010-01-01 05:00:00",
+    "Tomorrow 17:30 o'clock" = "2010-01-02 17:30:00",
+    "24.12.2025" = "2025-12-24 00:00:00",
+    "24.12." = "2010-12-24 00:00:00",
+    "3. March 2026" = "2026-03-03 00:00:00",
+    "Before 3 days" = "2009-12-29 00:00:00",
+    "Before 2 days at 10:00" = "2009-12-30 10:00:00",
+    "This weekend" = "2010-01-02 00:00:00",
+    "The weekend" = "2010-01-02 00:00:00",
+    "Next weekend" = "2010-01-09 00:00:00",
+    "Last weekend" = "2009-12-26 00:00:00",
+    "Weekend 10:00" = "2010-01-02 10:00:00",
+    "This workweek" = "2009-12-28 00:00:00",
+    "Next work week" = "2010-01-04 00:00:00",
+    "Last weekdays" = "2009-12-21 00:00:00",
+    "Christmas" = "2010-12-25 00:00:00",
+    "Easter 2026" = "2026-04-05 00:00:00",
+    "Good Friday 2025" = "2025-04-18 00:00:00",
+    "Before Easter" = "2010-04-03 00:00:00",
+    "2 days before Christmas" = "2010-12-23 00:00:00",
+    "A week after Easter Monday at 10:00" = "2010-04-12 10:00:00",
+    "The day before yesterday" = "2009-12-30 00:00:00",
+    "Last Christmas" = "2009-12-25 00:00:00",
+    "Next New Years Day" = "2011-01-01 00:00:00",
+    "Friday next" = "2010-01-08 00:00:00",
+    "Friday next week" = "2010-01-08 00:00:00",
+    "Month last" = "2009-12-01 00:00:00",
+    "Christmas last" = "2009-12-25 00:00:00",
+    "Next Friday at 5" = "2010-01-08 05:00:00",
+    "Tomorrow at 17h" = "2010-01-02 17:00:00",
+    "Tomorrow at 17h30" = "2010-01-02 17:30:00",
+    "3h ago" = "2009-12-31 21:00:00"
+);
+
+#[cfg(feature = "holidays-us")]
+generate_test_cases!(
+    "The day after Thanksgiving" = "2010-11-26 00:00:00",
+    "Memorial Day 2024" = "2024-05-27 00:00:00",
+    "Labor Day" = "2010-09-06 00:00:00"
+);
+
+generate_range_test_cases!(
+    "From Monday to Friday" = ("2010-01-04 00:00:00", "2010-01-08 00:00:00"),
+    "Today until Overmorrow" = ("2010-01-01 00:00:00", "2010-01-03 00:00:00"),
+    "2010-01-05 - 2010-01-09" = ("2010-01-05 00:00:00", "2010-01-09 00:00:00"),
+    "March 3-7" = ("2010-03-03 00:00:00", "2010-03-07 00:00:00"),
+    "March 3 to 7, 2012" = ("2012-03-03 00:00:00", "2012-03-07 00:00:00"),
+    "3-7 March 2011" = ("2011-03-03 00:00:00", "2011-03-07 00:00:00"),
+    "9:00-17:00" = ("2010-01-01 09:00:00", "2010-01-01 17:00:00"),
+    "Between 9:00 and 17:30" = ("2010-01-01 09:00:00", "2010-01-01 17:30:00"),
+    "Between 9 and 5" = ("2010-01-01 09:00:00", "2010-01-01 17:00:00"),
+    "From 8 to 12" = ("2010-01-01 08:00:00", "2010-01-01 12:00:00"),
+    "Today 9:00 until 17:00" = ("2010-01-01 09:00:00", "2010-01-01 17:00:00"),
+    "From Today until Friday 12:00" = ("2010-01-01 00:00:00", "2010-01-08 12:00:00"),
+    "From 2 hours ago to now" = ("2009-12-31 22:00:00", "2010-01-01 00:00:00"),
+    "Yesterday 18:00 - Tomorrow" = ("2009-12-31 18:00:00", "2010-01-02 23:59:59"),
+    "From Friday to Monday" = ("2010-01-08 00:00:00", "2010-01-11 00:00:00"),
+    "Saturday - Tuesday" = ("2010-01-02 00:00:00", "2010-01-05 00:00:00"),
+    "From 28 December to 3 January" = ("2010-12-28 00:00:00", "2011-01-03 00:00:00"),
+    "30 January - 2 February" = ("2010-01-30 00:00:00", "2010-02-02 00:00:00"),
+    "Friday 22:00 until 2:00" = ("2010-01-08 22:00:00", "2010-01-09 02:00:00")
+);
+
+generate_period_test_cases!(
+    "Yesterday" = ("2009-12-31 00:00:00", "2009-12-31 00:00:00"),
+    "This week" = ("2009-12-28 00:00:00", "2010-01-03 00:00:00"),
+    "Next week" = ("2010-01-04 00:00:00", "2010-01-10 00:00:00"),
+    "Last month" = ("2009-12-01 00:00:00", "2009-12-31 00:00:00"),
+    "Next month" = ("2010-02-01 00:00:00", "2010-02-28 00:00:00"),
+    "This year" = ("2010-01-01 00:00:00", "2010-12-31 00:00:00"),
+    "Last year" = ("2009-01-01 00:00:00", "2009-12-31 00:00:00"),
+    "This quarter" = ("2010-01-01 00:00:00", "2010-03-31 00:00:00"),
+    "Last quarter" = ("2009-10-01 00:00:00", "2009-12-31 00:00:00"),
+    "Q2" = ("2010-04-01 00:00:00", "2010-06-30 00:00:00"),
+    "Q3 2024" = ("2024-07-01 00:00:00", "2024-09-30 00:00:00"),
+    "Today 18:30" = ("2010-01-01 18:30:00", "2010-01-01 18:30:00"),
+    "March 3-7" = ("2010-03-03 00:00:00", "2010-03-07 00:00:00"),
+    "2025" = ("2025-01-01 00:00:00", "2025-12-31 00:00:00"),
+    "February 2012" = ("2012-02-01 00:00:00", "2012-02-29 00:00:00"),
+    "Last December" = ("2009-12-01 00:00:00", "2009-12-31 00:00:00"),
+    "In June" = ("2010-06-01 00:00:00", "2010-06-30 00:00:00"),
+    "This weekend" = ("2010-01-02 00:00:00", "2010-01-03 00:00:00"),
+    "Last weekend" = ("2009-12-26 00:00:00", "2009-12-27 00:00:00"),
+    "Next workweek" = ("2010-01-04 00:00:00", "2010-01-08 00:00:00"),
+    "Weekdays" = ("2009-12-28 00:00:00", "2010-01-01 00:00:00")
+);
+
+generate_recurrence_test_cases!(
+    "Every Monday at 9" = ["2010-01-04 09:00:00", "2010-01-11 09:00:00", "2010-01-18 09:00:00"];
+    "Every Monday and Friday at 17:30" = ["2010-01-01 17:30:00", "2010-01-04 17:30:00", "2010-01-08 17:30:00"];
+    "Every weekday at 8:30" = ["2010-01-04 08:30:00", "2010-01-05 08:30:00", "2010-01-06 08:30:00", "2010-01-07 08:30:00", "2010-01-08 08:30:00", "2010-01-11 08:30:00"];
+    "Every other Tuesday" = ["2010-01-05 12:00:00", "2010-01-19 12:00:00", "2010-02-02 12:00:00"];
+    "Every 2 weeks on Monday and Wednesday" = ["2010-01-04 12:00:00", "2010-01-06 12:00:00", "2010-01-18 12:00:00"];
+    "Every day" = ["2010-01-02 12:00:00", "2010-01-03 12:00:00"];
+    "Daily at 9" = ["2010-01-02 09:00:00", "2010-01-03 09:00:00"];
+    "Every 3 days at 15:00" = ["2010-01-01 15:00:00", "2010-01-04 15:00:00", "2010-01-07 15:00:00"];
+    "Every 15 minutes" = ["2010-01-01 12:15:00", "2010-01-01 12:30:00"];
+    "Hourly" = ["2010-01-01 13:00:00", "2010-01-01 14:00:00"];
+    "Every quarter" = ["2010-04-01 12:00:00", "2010-07-01 12:00:00"];
+    "On the 1st of each month" = ["2010-02-01 12:00:00", "2010-03-01 12:00:00"];
+    "The 31st of every month at 8:00" = ["2010-01-31 08:00:00", "2010-03-31 08:00:00", "2010-05-31 08:00:00"];
+    "Every 2 months on the 15th" = ["2010-01-15 12:00:00", "2010-03-15 12:00:00"];
+    "Monthly on the 10th" = ["2010-01-10 12:00:00", "2010-02-10 12:00:00"];
+    "Every first Monday of the month at 10" = ["2010-01-04 10:00:00", "2010-02-01 10:00:00", "2010-03-01 10:00:00"];
+    "On the last Friday of every month" = ["2010-01-29 12:00:00", "2010-02-26 12:00:00"];
+    "Every month on the 2nd Tuesday" = ["2010-01-12 12:00:00", "2010-02-09 12:00:00"];
+    "Every Monday at 9 for 3 times" = ["2010-01-04 09:00:00", "2010-01-11 09:00:00", "2010-01-18 09:00:00"], ends;
+    "Every day at 8:00 until 2010-01-03" = ["2010-01-02 08:00:00", "2010-01-03 08:00:00"], ends;
+    "Every Friday until next week Sunday" = ["2010-01-08 12:00:00"], ends
 );
 
+generate_recurrence_export_test_cases!(
+    "Every Monday at 9" = (
+        "DTSTART:20100104T090000\nRRULE:FREQ=WEEKLY;BYDAY=MO;BYHOUR=9;BYMINUTE=0",
+        Some("0 9 * * 1")
+    ),
+    "Every weekday at 8:30" = (
+        "DTSTART:20100104T083000\nRRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR;BYHOUR=8;BYMINUTE=30",
+        Some("30 8 * * 1,2,3,4,5")
+    ),
+    "Every Saturday and Sunday at 10:15" = (
+        "DTSTART:20100102T101500\nRRULE:FREQ=WEEKLY;BYDAY=SA,SU;BYHOUR=10;BYMINUTE=15",
+        Some("15 10 * * 0,6")
+    ),
+    "Every other Tuesday" = (
+        "DTSTART:20100105T120000\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU;BYHOUR=12;BYMINUTE=0",
+        None
+    ),
+    "Every day" = (
+        "DTSTART:20100102T120000\nRRULE:FREQ=DAILY;BYHOUR=12;BYMINUTE=0",
+        Some("0 12 * * *")
+    ),
+    "Every 3 days" = (
+        "DTSTART:20100104T120000\nRRULE:FREQ=DAILY;INTERVAL=3;BYHOUR=12;BYMINUTE=0",
+        None
+    ),
+    "Every 15 minutes" = (
+        "DTSTART:20100101T121500\nRRULE:FREQ=MINUTELY;INTERVAL=15",
+        Some("0,15,30,45 * * * *")
+    ),
+    "Every 7 minutes" = (
+        "DTSTART:20100101T120700\nRRULE:FREQ=MINUTELY;INTERVAL=7",
+        None
+    ),
+    "Every 6 hours" = (
+        "DTSTART:20100101T180000\nRRULE:FREQ=HOURLY;INTERVAL=6",
+        Some("0 0,6,12,18 * * *")
+    ),
+    "Every 30 seconds" = (
+        "DTSTART:20100101T120030\nRRULE:FREQ=SECONDLY;INTERVAL=30",
+        None
+    ),
+    "Every quarter" = (
+        "DTSTART:20100401T120000\nRRULE:FREQ=MONTHLY;INTERVAL=3;BYHOUR=12;BYMINUTE=0",
+        Some("0 12 1 1,4,7,10 *")
+    ),
+    "Every year" = (
+        "DTSTART:20110101T120000\nRRULE:FREQ=YEARLY;BYHOUR=12;BYMINUTE=0",
+        Some("0 12 1 1 *")
+    ),
+    "On the 15th of each month at 18:00" = (
+        "DTSTART:20100115T180000\nRRULE:FREQ=MONTHLY;BYMONTHDAY=15;BYHOUR=18;BYMINUTE=0",
+        Some("0 18 15 * *")
+    ),
+    "Every 6 months on the 1st" = (
+        "DTSTART:20100201T120000\nRRULE:FREQ=MONTHLY;INTERVAL=6;BYMONTHDAY=1;BYHOUR=12;BYMINUTE=0",
+        Some("0 12 1 2,8 *")
+    ),
+    "Every last Friday of the month" = (
+        "DTSTART:20100129T120000\nRRULE:FREQ=MONTHLY;BYDAY=-1FR;BYHOUR=12;BYMINUTE=0",
+        None
+    ),
+    "Every first Monday of the month at 9" = (
+        "DTSTART:20100104T090000\nRRULE:FREQ=MONTHLY;BYDAY=1MO;BYHOUR=9;BYMINUTE=0",
+        None
+    ),
+    "Every Monday for 5 times" = (
+        "DTSTART:20100104T120000\nRRULE:FREQ=WEEKLY;BYDAY=MO;BYHOUR=12;BYMINUTE=0;COUNT=5",
+        None
+    ),
+    "Every day until 2010-02-01" = (
+        "DTSTART:20100102T120000\nRRULE:FREQ=DAILY;BYHOUR=12;BYMINUTE=0;UNTIL=20100201T235959",
+        None
+    ),
+    "Every day at 8:00:30" = (
+        "DTSTART:20100102T080030\nRRULE:FREQ=DAILY;BYHOUR=8;BYMINUTE=0;BYSECOND=30",
+        None
+    )
+);
+
+generate_format_test_cases!(
+    "2010-01-01 00:00:00" = "now",
+    "2010-01-01 02:00:00" = "in 2 hours",
+    "2009-12-31 22:00:00" = "2 hours ago",
+    "2010-01-01 00:05:30" = "in 5 minutes and 30 seconds",
+    "2010-01-01 18:30:00" = "today at 18:30",
+    "2009-12-31 09:00:00" = "yesterday at 09:00",
+    "2010-01-02 09:15:00" = "tomorrow at 09:15",
+    "2010-01-03 18:30:45" = "overmorrow at 18:30:45",
+    "2010-01-05 17:00:00" = "next Tuesday at 17:00",
+    "2009-12-26 08:00:00" = "last Saturday at 08:00",
+    "2010-01-08 00:00:00" = "in 1 week",
+    "2010-01-21 12:00:00" = "in 2 weeks, 6 days and 12 hours",
+    "2010-03-01 00:00:00" = "in 2 months",
+    "2009-10-01 00:00:00" = "3 months ago",
+    "2008-11-22 22:58:59" = "1 year, 1 month, 1 week, 1 day, 1 hour, 1 minute and 1 second ago"
+);
+
+#[test]
+fn format_with_options() {
+    let now = now();
+    let at = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+    let options = |granularity, max_units| FormatOptions {
+        granularity,
+        max_units,
+        ..FormatOptions::default()
+    };
+    let sunday_start = FormatOptions {
+        granularity: Unit::Week,
+        week_start: chrono::Weekday::Sun,
+        ..FormatOptions::default()
+    };
+
+    let cases = [
+        ("2010-01-08 15:00:00", options(Unit::Day, None), "in 1 week"),
+        (
+            "2010-01-05 15:00:00",
+            options(Unit::Day, None),
+            "next Tuesday",
+        ),
+        ("2010-01-01 23:59:00", options(Unit::Day, None), "today"),
+        (
+            "2010-01-01 14:45:10",
+            options(Unit::Hour, None),
+            "in 2 hours",
+        ),
+        (
+            "2010-01-01 14:45:10",
+            options(Unit::Minute, None),
+            "in 2 hours and 45 minutes",
+        ),
+        (
+            "2011-03-15 00:00:00",
+            options(Unit::Second, Some(2)),
+            "in 1 year and 2 months",
+        ),
+        (
+            "2010-01-20 00:00:00",
+            options(Unit::Week, None),
+            "in 3 weeks",
+        ),
+        (
+            "2010-02-20 00:00:00",
+            options(Unit::Month, None),
+            "next month",
+        ),
+        (
+            "2009-12-31 00:00:00",
+            options(Unit::Month, None),
+            "last month",
+        ),
+        (
+            "2010-12-31 00:00:00",
+            options(Unit::Year, None),
+            "this year",
+        ),
+        (
+            "2013-06-01 00:00:00",
+            options(Unit::Year, None),
+            "in 3 years",
+        ),
+        (
+            "2010-01-03 00:00:00",
+            options(Unit::Week, None),
+            "this week",
+        ),
+        ("2010-01-03 00:00:00", sunday_start, "next week"),
+        (
+            "2009-12-27 00:00:00",
+            options(Unit::Week, None),
+            "last week",
+        ),
+        ("2009-12-27 00:00:00", sunday_start, "this week"),
+    ];
+
+    for (target, options, expected) in cases {
+        let result = to_human_time(at(target), now, options);
+        println!("Result: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    // Only phrases that every weekday policy reads the same are used with other policies.
+    let calendar_week = FormatOptions {
+        granularity: Unit::Day,
+        weekday_policy: WeekdayPolicy::CalendarWeek,
+        ..FormatOptions::default()
+    };
+    let monday = at("2010-01-04 12:00:00");
+    let cases = [
+        ("2010-01-05 15:00:00", now, "in 4 days"),
+        ("2009-12-26 15:00:00", now, "6 days ago"),
+        ("2010-01-07 15:00:00", monday, "this Thursday"),
+    ];
+    for (target, now, expected) in cases {
+        let result = to_human_time(at(target), now, calendar_week);
+        println!("Result: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    // The phrase names the week the parser reads it as.
+    let parser = crate::Parser::new().week_start(chrono::Weekday::Sun);
+    assert_eq!(
+        parser.parse_period("next week", now).unwrap(),
+        ParseRange::Date(
+            NaiveDate::from_ymd_opt(2010, 1, 3).unwrap(),
+            NaiveDate::from_ymd_opt(2010, 1, 9).unwrap()
+        )
+    );
+}
+
+#[test]
+fn humanize_durations() {
+    let options = |granularity, max_units, abbreviate, rounding, conjunction| DurationOptions {
+        granularity,
+        max_units,
+        abbreviate,
+        rounding,
+        conjunction,
+    };
+    let default = DurationOptions::default();
+    let duration = |h, m, s| {
+        chrono::Duration::hours(h) + chrono::Duration::minutes(m) + chrono::Duration::seconds(s)
+    };
+
+    let cases = [
+        (
+            duration(2, 32, 7),
+            default,
+            "2 hours, 32 minutes and 7 seconds",
+        ),
+        (duration(0, 0, 0), default, "0 seconds"),
+        (duration(0, 0, 1), default, "1 second"),
+        (-duration(1, 0, 0), default, "1 hour"),
+        (duration(200, 0, 0), default, "1 week, 1 day and 8 hours"),
+        (
+            duration(2, 5, 0),
+            options(Unit::Second, None, true, Rounding::Down, Conjunction::Space),
+            "2h 5m",
+        ),
+        (
+            duration(2, 5, 0),
+            options(Unit::Second, None, true, Rounding::Down, Conjunction::And),
+            "2h 5m",
+        ),
+        (
+            duration(26, 5, 0),
+            options(Unit::Second, None, true, Rounding::Down, Conjunction::Comma),
+            "1d, 2h, 5m",
+        ),
+        (
+            duration(26, 5, 0),
+            options(
+                Unit::Second,
+                None,
+                false,
+                Rounding::Down,
+                Conjunction::Comma,
+            ),
+            "1 day, 2 hours, 5 minutes",
+        ),
+        (
+            duration(1, 50, 0),
+            options(
+                Unit::Second,
+                Some(1),
+                false,
+                Rounding::Down,
+                Conjunction::And,
+            ),
+            "1 hour",
+        ),
+        (
+            duration(1, 50, 0),
+            options(
+                Unit::Second,
+                Some(1),
+                false,
+                Rounding::Nearest,
+                Conjunction::And,
+            ),
+            "2 hours",
+        ),
+        (
+            duration(1, 10, 0),
+            options(Unit::Second, Some(1), false, Rounding::Up, Conjunction::And),
+            "2 hours",
+        ),
+        (
+            duration(1, 59, 45),
+            options(
+                Unit::Minute,
+                None,
+                false,
+                Rounding::Nearest,
+                Conjunction::And,
+            ),
+            "2 hours",
+        ),
+        (
+            duration(0, 0, 40),
+            options(Unit::Minute, None, true, Rounding::Down, Conjunction::And),
+            "0m",
+        ),
+        (
+            duration(1, 0, 30),
+            options(
+                Unit::Second,
+                Some(2),
+                false,
+                Rounding::Down,
+                Conjunction::And,
+            ),
+            "1 hour",
+        ),
+    ];
+
+    for (duration, options, expected) in cases {
+        let result = humanize_duration(duration, options);
+        println!("Result: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn humanize_durations_between() {
+    let at = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+    let options = |granularity, max_units, rounding| DurationOptions {
+        granularity,
+        max_units,
+        rounding,
+        ..DurationOptions::default()
+    };
+
+    let cases = [
+        (
+            "2010-01-01 00:00:00",
+            "2010-01-01 00:00:00",
+            DurationOptions::default(),
+            "0 seconds",
+        ),
+        (
+            "2010-01-01 00:00:00",
+            "2011-03-04 05:06:07",
+            DurationOptions::default(),
+            "1 year, 2 months, 3 days, 5 hours, 6 minutes and 7 seconds",
+        ),
+        (
+            "2011-03-04 00:00:00",
+            "2010-01-01 00:00:00",
+            DurationOptions::default(),
+            "1 year, 2 months and 3 days",
+        ),
+        (
+            "2010-01-31 00:00:00",
+            "2010-02-28 00:00:00",
+            DurationOptions::default(),
+            "1 month",
+        ),
+        (
+            "2010-01-01 00:00:00",
+            "2010-12-20 00:00:00",
+            options(Unit::Month, None, Rounding::Nearest),
+            "1 year",
+        ),
+        (
+            "2010-01-01 00:00:00",
+            "2010-12-20 00:00:00",
+            options(Unit::Month, None, Rounding::Down),
+            "11 months",
+        ),
+        (
+            "2010-01-01 00:00:00",
+            "2010-02-01 00:00:01",
+            options(Unit::Day, None, Rounding::Up),
+            "1 month and 1 day",
+        ),
+        (
+            "2010-01-01 00:00:00",
+            "2010-03-20 00:00:00",
+            options(Unit::Second, Some(1), Rounding::Nearest),
+            "3 months",
+        ),
+    ];
+
+    for (from, to, options, expected) in cases {
+        let result = humanize_duration_between(at(from), at(to), options);
+        println!("Result: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn format_round_trip() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2012, 2, 29).unwrap(),
+        NaiveTime::from_hms_opt(13, 37, 0).unwrap(),
+    );
+
+    let week = |dt: NaiveDateTime| dt.date().week(chrono::Weekday::Mon).first_day();
+    let parsers = [
+        WeekdayPolicy::NearestUpcoming,
+        WeekdayPolicy::CalendarWeek,
+        WeekdayPolicy::SkipOne,
+    ]
+    .map(|policy| crate::Parser::new().weekday_policy(policy));
+
+    // Steps through roughly three years around `now` in uneven steps, so that many different
+    // phrases get produced. Results are only compared down to the granularity they were
+    // formatted at.
+    for step in -1500..1500 {
+        let target = now + chrono::Duration::minutes(step * 1051);
+        // The weekday policy only matters for phrases about the days around `now`.
+        let parsers = match (target - now).num_days().abs() {
+            0..=7 => &parsers[..],
+            _ => &parsers[..1],
+        };
+        for granularity in [
+            Unit::Year,
+            Unit::Month,
+            Unit::Week,
+            Unit::Day,
+            Unit::Hour,
+            Unit::Minute,
+            Unit::Second,
+        ] {
+            for parser in parsers {
+                let options = FormatOptions {
+                    granularity,
+                    weekday_policy: parser.options().weekday_policy,
+                    ..FormatOptions::default()
+                };
+                let result = round_trip(target, now, options, parser);
+                let expected = match granularity {
+                    Unit::Year => target.year() == result.year(),
+                    Unit::Month => {
+                        target.year() == result.year() && target.month() == result.month()
+                    }
+                    Unit::Week => week(target) == week(result),
+                    Unit::Day => target.date() == result.date(),
+                    Unit::Hour => target.date() == result.date() && target.hour() == result.hour(),
+                    _ => (target - result).num_seconds().abs() < 60 || target == result,
+                };
+                assert!(
+                    expected,
+                    "{target} was formatted as \"{}\" and parsed back as {result} by {:?}",
+                    to_human_time(target, now, options),
+                    parser.options().weekday_policy
+                );
+            }
+        }
+    }
+}
+
 generate_test_cases_error!("2023-11-31");
+generate_test_cases_error!("2010-01-05 - 2010-01-03");
+generate_test_cases_error!("17:00 - 9:00");
+generate_test_cases_error!("March 3-7 10");
+generate_test_cases_error!("13.13.");
+generate_test_cases_error!("December 25");
+generate_test_cases_error!("Married", "Junk", "Feburary", "In Marching", "3 Decembers");
+
+#[test]
+fn fail_parse_recurrence() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+    );
+
+    for input in [
+        "every 0 days",
+        "every hour at 9",
+        "on the 32nd of every month",
+        "every 2000000000 quarters",
+        "every",
+    ] {
+        let result = from_human_recurrence(input, now);
+        println!("Input: {input}\nResult: {result:#?}\nExpected: Error");
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn recurrence_interval_too_large() {
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+    );
+
+    let result = from_human_recurrence("every 2000000000 quarters", now);
+    assert!(matches!(
+        result,
+        Err(ParseError::ProccessingErrors(errors))
+            if matches!(errors[..], [ProcessingError::IntervalTooLarge { interval: 2000000000, .. }])
+    ));
+
+    let result = from_human_recurrence("every 4294967295 days", now);
+    assert!(matches!(
+        result,
+        Err(ParseError::ProccessingErrors(errors))
+            if matches!(errors[..], [ProcessingError::IntervalTooLarge { interval: 4294967295, .. }])
+    ));
+
+    let result = from_human_recurrence("every 401 years", now);
+    assert!(matches!(
+        result,
+        Err(ParseError::ProccessingErrors(errors))
+            if matches!(errors[..], [ProcessingError::IntervalTooLarge { interval: 401, .. }])
+    ));
+
+    let recurrence = from_human_recurrence("every 146097 days", now).unwrap();
+    assert_eq!(
+        recurrence.occurrences_after(now).next(),
+        Some(NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(2410, 1, 1).unwrap(),
+            NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+        ))
+    );
+}
+
+#[test]
+fn rrule_matches_occurrences() {
+    let now = now();
+    let default = crate::Parser::new();
+    let sunday = crate::Parser::new().week_start(chrono::Weekday::Sun);
+
+    for (parser, input) in [
+        (&default, "Every Monday at 9"),
+        (&default, "Every other Tuesday"),
+        (&default, "Every 2 weeks on Monday and Wednesday"),
+        (&sunday, "Every other Sunday and Monday at 9"),
+        (&default, "Every day"),
+        (&default, "Every 3 days at 15:00"),
+        (&default, "Every 15 minutes"),
+        (&default, "Every 2 months on the 1st"),
+        (&default, "Every 2 months"),
+        (&default, "Every quarter"),
+        (&default, "Every year"),
+        (&default, "The 31st of every month at 8:00"),
+        (&default, "Every first Monday of the month at 10"),
+        (&default, "On the last Friday of every month"),
+        (&default, "Every Monday at 9 for 3 times"),
+        (&default, "Every day at 8:00 until 2010-01-03"),
+    ] {
+        let recurrence = parser.parse_recurrence(input, now).unwrap();
+        let rrule = recurrence.to_rrule().unwrap();
+        let expected: Vec<NaiveDateTime> = recurrence.occurrences_after(now).take(10).collect();
+
+        let result = expand_rrule(&rrule, 10);
+        println!("Input: {input}\nRRULE: {rrule}\nResult: {result:?}\nExpected: {expected:?}");
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn rrule_errors() {
+    use crate::RruleError;
+
+    let at = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+
+    let recurrence = from_human_recurrence("Every day at 8:00", at("9999-12-31 12:00:00")).unwrap();
+    assert_eq!(
+        recurrence.to_rrule(),
+        Err(RruleError::YearOutOfRange {
+            date: NaiveDate::from_ymd_opt(10000, 1, 1)
+                .unwrap()
+                .and_hms_opt(8, 0, 0)
+                .unwrap()
+        })
+    );
+
+    // No month has a 32nd day.
+    let recurrence = crate::Recurrence {
+        start: at("2010-01-01 12:00:00"),
+        pattern: crate::RecurrencePattern::MonthlyOnDay {
+            interval: 1,
+            day: 32,
+        },
+        time: None,
+        limit: None,
+        week_start: Weekday::Mon,
+    };
+    assert_eq!(recurrence.occurrences_after(recurrence.start).next(), None);
+    assert_eq!(recurrence.to_rrule(), Err(RruleError::NoOccurrences));
+}
+
+#[test]
+fn parser_matches_free_functions() {
+    let now = now();
+    let parser = crate::Parser::with_options(ParseOptions::default());
+    assert_eq!(parser.options(), &ParseOptions::default());
+
+    for input in [
+        "Last Friday at 19:45",
+        "In 3 days",
+        "From Monday to Friday",
+        "Q2",
+    ] {
+        assert_eq!(
+            parser.parse(input, now).unwrap(),
+            from_human_time(input, now).unwrap()
+        );
+        assert_eq!(
+            parser.parse_period(input, now).unwrap(),
+            from_human_period(input, now).unwrap()
+        );
+    }
+
+    assert_eq!(
+        parser.parse_recurrence("Every Monday at 9", now).unwrap(),
+        from_human_recurrence("Every Monday at 9", now).unwrap()
+    );
+    assert!(parser.parse("Not a date", now).is_err());
+}
+
+#[test]
+fn week_start() {
+    let now = now();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let monday = crate::Parser::new();
+    let sunday = crate::Parser::new().week_start(chrono::Weekday::Sun);
+
+    assert_eq!(
+        monday.parse("This week Sunday", now).unwrap(),
+        ParseResult::Date(date(2010, 1, 3))
+    );
+    assert_eq!(
+        sunday.parse("This week Sunday", now).unwrap(),
+        ParseResult::Date(date(2009, 12, 27))
+    );
+    assert_eq!(
+        sunday.parse("Next week Sunday", now).unwrap(),
+        ParseResult::Date(date(2010, 1, 3))
+    );
+    assert_eq!(
+        sunday.parse("Last week Saturday", now).unwrap(),
+        ParseResult::Date(date(2009, 12, 26))
+    );
+    assert_eq!(
+        monday.parse_period("This week", now).unwrap(),
+        ParseRange::Date(date(2009, 12, 28), date(2010, 1, 3))
+    );
+    assert_eq!(
+        sunday.parse_period("This week", now).unwrap(),
+        ParseRange::Date(date(2009, 12, 27), date(2010, 1, 2))
+    );
+
+    let cases = [
+        ("Start of week", date(2009, 12, 28), date(2009, 12, 27)),
+        (
+            "The beginning of the week",
+            date(2009, 12, 28),
+            date(2009, 12, 27),
+        ),
+        ("End of the week", date(2010, 1, 3), date(2010, 1, 2)),
+        ("Start of next week", date(2010, 1, 4), date(2010, 1, 3)),
+        ("The end of next week", date(2010, 1, 10), date(2010, 1, 9)),
+        ("End of last week", date(2009, 12, 27), date(2009, 12, 26)),
+    ];
+    for (input, on_monday, on_sunday) in cases {
+        assert_eq!(
+            monday.parse(input, now).unwrap(),
+            ParseResult::Date(on_monday),
+            "{input}"
+        );
+        assert_eq!(
+            sunday.parse(input, now).unwrap(),
+            ParseResult::Date(on_sunday),
+            "{input}"
+        );
+    }
+
+    let occurrences = |parser: &crate::Parser| {
+        let recurrence = parser
+            .parse_recurrence("Every other Sunday and Monday at 9", now)
+            .unwrap();
+        recurrence
+            .occurrences_after(now)
+            .take(4)
+            .map(|occurrence| occurrence.date())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        occurrences(&monday),
+        [
+            date(2010, 1, 3),
+            date(2010, 1, 11),
+            date(2010, 1, 17),
+            date(2010, 1, 25)
+        ]
+    );
+    assert_eq!(
+        occurrences(&sunday),
+        [
+            date(2010, 1, 3),
+            date(2010, 1, 4),
+            date(2010, 1, 17),
+            date(2010, 1, 18)
+        ]
+    );
+
+    let recurrence = sunday
+        .parse_recurrence("Every other Sunday and Monday at 9", now)
+        .unwrap();
+    assert_eq!(
+        recurrence.to_rrule().unwrap(),
+        "DTSTART:20100103T090000\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,SU;WKST=SU;BYHOUR=9;BYMINUTE=0"
+    );
+}
+
+#[test]
+fn prefer_future_or_past() {
+    use crate::{Parser, Preference};
+
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 5, 15).unwrap(),
+        NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+    );
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+    let current = Parser::new();
+    let future = Parser::new().prefer(Preference::Future);
+    let past = Parser::new().prefer(Preference::Past);
+
+    let cases = [
+        ("Friday", &current, ParseResult::Date(date(2010, 5, 21))),
+        ("Friday", &future, ParseResult::Date(date(2010, 5, 21))),
+        ("Friday", &past, ParseResult::Date(date(2010, 5, 14))),
+        ("7 February", &current, ParseResult::Date(date(2010, 2, 7))),
+        ("7 February", &future, ParseResult::Date(date(2011, 2, 7))),
+        ("7 February", &past, ParseResult::Date(date(2010, 2, 7))),
+        ("7 December", &future, ParseResult::Date(date(2010, 12, 7))),
+        ("7 December", &past, ParseResult::Date(date(2009, 12, 7))),
+        ("15 May", &future, ParseResult::Date(date(2010, 5, 15))),
+        ("15 May", &past, ParseResult::Date(date(2010, 5, 15))),
+        ("29 February", &future, ParseResult::Date(date(2012, 2, 29))),
+        ("29 February", &past, ParseResult::Date(date(2008, 2, 29))),
+        ("17:00", &current, ParseResult::Time(time(17, 0))),
+        (
+            "17:00",
+            &future,
+            ParseResult::DateTime(date(2010, 5, 15).and_time(time(17, 0))),
+        ),
+        (
+            "17:00",
+            &past,
+            ParseResult::DateTime(date(2010, 5, 14).and_time(time(17, 0))),
+        ),
+        (
+            "9:00",
+            &future,
+            ParseResult::DateTime(date(2010, 5, 16).and_time(time(9, 0))),
+        ),
+        (
+            "9:00",
+            &past,
+            ParseResult::DateTime(date(2010, 5, 15).and_time(time(9, 0))),
+        ),
+        (
+            "13:00 7 February",
+            &future,
+            ParseResult::DateTime(date(2011, 2, 7).and_time(time(13, 0))),
+        ),
+        (
+            "March 3-7",
+            &current,
+            ParseResult::Range(ParseRange::Date(date(2010, 3, 3), date(2010, 3, 7))),
+        ),
+        (
+            "March 3-7",
+            &future,
+            ParseResult::Range(ParseRange::Date(date(2011, 3, 3), date(2011, 3, 7))),
+        ),
+        (
+            "December 3-7",
+            &past,
+            ParseResult::Range(ParseRange::Date(date(2009, 12, 3), date(2009, 12, 7))),
+        ),
+        (
+            "9:00-17:00",
+            &future,
+            ParseResult::Range(ParseRange::Time(time(9, 0), time(17, 0))),
+        ),
+    ];
+
+    for (input, parser, expected) in cases {
+        let result = parser.parse(input, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    assert!(current.parse("29 February", now).is_err());
+}
+
+#[test]
+fn weekday_policies() {
+    use crate::{Parser, WeekdayPolicy};
+
+    // 2010-01-01 is a Friday.
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+    );
+    let date = |y, m, d| ParseResult::Date(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+    let nearest = Parser::new();
+    let calendar = Parser::new().weekday_policy(WeekdayPolicy::CalendarWeek);
+    let sunday_calendar = Parser::new()
+        .weekday_policy(WeekdayPolicy::CalendarWeek)
+        .week_start(chrono::Weekday::Sun);
+    let skip = Parser::new().weekday_policy(WeekdayPolicy::SkipOne);
+
+    let cases = [
+        ("Next Saturday", &nearest, date(2010, 1, 2)),
+        ("This Saturday", &nearest, date(2010, 1, 2)),
+        ("Next Friday", &nearest, date(2010, 1, 8)),
+        ("This Friday", &nearest, date(2010, 1, 1)),
+        ("Next Monday", &nearest, date(2010, 1, 4)),
+        ("Last Friday", &nearest, date(2009, 12, 25)),
+        ("Last Thursday", &nearest, date(2009, 12, 31)),
+        ("Next Saturday", &calendar, date(2010, 1, 9)),
+        ("This Saturday", &calendar, date(2010, 1, 2)),
+        ("Next Friday", &calendar, date(2010, 1, 8)),
+        ("This Friday", &calendar, date(2010, 1, 1)),
+        ("Next Monday", &calendar, date(2010, 1, 4)),
+        ("This Monday", &calendar, date(2009, 12, 28)),
+        ("Last Friday", &calendar, date(2009, 12, 25)),
+        ("Last Thursday", &calendar, date(2009, 12, 24)),
+        ("Next Saturday", &sunday_calendar, date(2010, 1, 9)),
+        ("Next Sunday", &sunday_calendar, date(2010, 1, 3)),
+        ("This Sunday", &sunday_calendar, date(2009, 12, 27)),
+        ("Next Saturday", &skip, date(2010, 1, 9)),
+        ("This Saturday", &skip, date(2010, 1, 2)),
+        ("Next Friday", &skip, date(2010, 1, 8)),
+        ("This Friday", &skip, date(2010, 1, 1)),
+        ("Next Monday", &skip, date(2010, 1, 11)),
+        ("Last Friday", &skip, date(2009, 12, 25)),
+        ("Last Thursday", &skip, date(2009, 12, 24)),
+        ("Saturday", &calendar, date(2010, 1, 2)),
+        ("Saturday", &skip, date(2010, 1, 2)),
+    ];
+
+    for (input, parser, expected) in cases {
+        let result = parser.parse(input, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn month_overflow() {
+    use crate::{MonthOverflow, Parser};
+
+    let at = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+    let clamp = Parser::new();
+    let roll_over = Parser::new().month_overflow(MonthOverflow::RollOver);
+    let error = Parser::new().month_overflow(MonthOverflow::Error);
+
+    let cases = [
+        (
+            "In 1 month",
+            "2010-01-31 12:00:00",
+            &clamp,
+            "2010-02-28 12:00:00",
+        ),
+        (
+            "In 1 month",
+            "2010-01-31 12:00:00",
+            &roll_over,
+            "2010-03-03 12:00:00",
+        ),
+        (
+            "In 1 month",
+            "2010-01-15 12:00:00",
+            &error,
+            "2010-02-15 12:00:00",
+        ),
+        (
+            "In 1 quarter",
+            "2010-01-31 12:00:00",
+            &clamp,
+            "2010-04-30 12:00:00",
+        ),
+        (
+            "In 1 quarter",
+            "2010-01-31 12:00:00",
+            &roll_over,
+            "2010-05-01 12:00:00",
+        ),
+        (
+            "In 1 year",
+            "2012-02-29 12:00:00",
+            &clamp,
+            "2013-02-28 12:00:00",
+        ),
+        (
+            "In 1 year",
+            "2012-02-29 12:00:00",
+            &roll_over,
+            "2013-03-01 12:00:00",
+        ),
+        (
+            "In 4 years",
+            "2012-02-29 12:00:00",
+            &error,
+            "2016-02-29 12:00:00",
+        ),
+        (
+            "1 month ago",
+            "2010-03-31 12:00:00",
+            &clamp,
+            "2010-02-28 12:00:00",
+        ),
+        (
+            "1 month ago",
+            "2010-03-31 12:00:00",
+            &roll_over,
+            "2010-03-03 12:00:00",
+        ),
+        (
+            "A year ago",
+            "2012-02-29 12:00:00",
+            &clamp,
+            "2011-02-28 12:00:00",
+        ),
+        (
+            "In 13 months",
+            "2010-01-31 12:00:00",
+            &clamp,
+            "2011-02-28 12:00:00",
+        ),
+        (
+            "25 months ago",
+            "2010-01-31 12:00:00",
+            &clamp,
+            "2007-12-31 12:00:00",
+        ),
+    ];
+
+    for (input, now, parser, expected) in cases {
+        let result = parser.parse(input, at(now)).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, ParseResult::DateTime(at(expected)));
+    }
+
+    let now = at("2010-01-31 12:00:00");
+    let date = |y, m, d| ParseResult::Date(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+    assert_eq!(clamp.parse("Next month", now).unwrap(), date(2010, 2, 28));
+    assert_eq!(
+        roll_over.parse("Next month", now).unwrap(),
+        date(2010, 3, 3)
+    );
+
+    for input in ["In 1 month", "Next month", "In 1 quarter"] {
+        assert!(error.parse(input, now).is_err());
+    }
+    assert!(error.parse("In 1 year", at("2012-02-29 12:00:00")).is_err());
+}
+
+#[test]
+fn format_round_trip_month_overflow() {
+    use crate::{MonthOverflow, Parser};
+
+    let at = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+    let now = at("2010-01-31 00:00:00");
+    let target = at("2010-03-02 00:00:00");
+    let options = FormatOptions {
+        granularity: Unit::Day,
+        ..FormatOptions::default()
+    };
+
+    // Phrases are only guaranteed to round trip with the default options.
+    let phrase = to_human_time(target, now, options);
+    assert_eq!(phrase, "in 1 month and 2 days");
+    assert_eq!(round_trip(target, now, options, &Parser::new()), target);
+    assert_eq!(
+        Parser::new()
+            .month_overflow(MonthOverflow::RollOver)
+            .parse(&phrase, now)
+            .unwrap(),
+        ParseResult::DateTime(at("2010-03-05 00:00:00"))
+    );
+    assert!(Parser::new()
+        .month_overflow(MonthOverflow::Error)
+        .parse(&phrase, now)
+        .is_err());
+}
+
+#[test]
+fn result_conversions() {
+    let now = now();
+    let date = NaiveDate::from_ymd_opt(2010, 1, 2).unwrap();
+    let time = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+    let at = |time: NaiveTime| Some(date.and_time(time));
+
+    let result = ParseResult::Date(date);
+    assert_eq!(
+        result.to_datetime(now, DefaultTime::StartOfDay),
+        at(NaiveTime::MIN)
+    );
+    assert_eq!(
+        result.to_datetime(now, DefaultTime::EndOfDay),
+        at(NaiveTime::from_hms_opt(23, 59, 59).unwrap())
+    );
+    assert_eq!(result.to_datetime(now, DefaultTime::Now), at(now.time()));
+    assert_eq!(result.to_datetime(now, DefaultTime::Fixed(time)), at(time));
+    assert_eq!(result.date(), Some(date));
+    assert_eq!(result.time(), None);
+
+    let result = ParseResult::Time(time);
+    assert_eq!(
+        result.to_datetime(now, DefaultTime::StartOfDay),
+        Some(now.date().and_time(time))
+    );
+    assert_eq!(result.date(), None);
+    assert_eq!(result.time(), Some(time));
+
+    let result = ParseResult::DateTime(date.and_time(time));
+    assert_eq!(result.to_datetime(now, DefaultTime::EndOfDay), at(time));
+    assert_eq!(result.date(), Some(date));
+    assert_eq!(result.time(), Some(time));
+    assert_eq!(NaiveDateTime::try_from(result), Ok(date.and_time(time)));
+
+    let range = ParseRange::Time(time, time);
+    let result = ParseResult::Range(range);
+    assert_eq!(result.to_datetime(now, DefaultTime::Now), None);
+    assert_eq!(result.date(), None);
+    assert_eq!(result.time(), None);
+    assert_eq!(ParseRange::try_from(result), Ok(range));
+
+    assert_eq!(NaiveDate::try_from(ParseResult::Date(date)), Ok(date));
+    assert_eq!(NaiveTime::try_from(ParseResult::Time(time)), Ok(time));
+    let error = NaiveDate::try_from(ParseResult::Time(time)).unwrap_err();
+    assert_eq!(error.result, ParseResult::Time(time));
+    assert_eq!(
+        error.to_string(),
+        "Can not convert 09:30:00 into a NaiveDate"
+    );
+}
+
+#[test]
+fn granularities() {
+    let now = now();
+    let parser = crate::Parser::new();
+
+    let cases = [
+        ("Next year", Granularity::Year),
+        ("Q2 2025", Granularity::Quarter),
+        ("Next quarter", Granularity::Quarter),
+        ("Next month", Granularity::Month),
+        ("2025", Granularity::Year),
+        ("March 2025", Granularity::Month),
+        ("Last December", Granularity::Month),
+        ("Last week", Granularity::Week),
+        ("Tomorrow", Granularity::Day),
+        ("Next Friday", Granularity::Day),
+        ("2022-11-07", Granularity::Day),
+        ("7 February", Granularity::Day),
+        ("In 3 days", Granularity::Day),
+        ("In 2 hours", Granularity::Hour),
+        ("10 hours and 5 minutes ago", Granularity::Minute),
+        ("Tomorrow 14:03", Granularity::Minute),
+        ("Tomorrow 14:03:22", Granularity::Second),
+        ("17:00", Granularity::Minute),
+        ("3 days ago 17:00", Granularity::Minute),
+        ("Now", Granularity::Subsecond),
+        ("March 3-7", Granularity::Day),
+        ("Between 9 and 5", Granularity::Hour),
+        ("From Monday to Friday 17:00", Granularity::Minute),
+    ];
+
+    for (input, expected) in cases {
+        let (result, granularity) = parser.parse_with_granularity(input, now).unwrap();
+        println!("Input: {input}\nResult: {granularity:?}\nExpected: {expected:?}");
+        assert_eq!(granularity, expected);
+        assert_eq!(result, parser.parse(input, now).unwrap());
+    }
+}
+
+#[test]
+fn custom_weekend() {
+    use chrono::Weekday;
+
+    let now = now();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let parser = crate::Parser::new().weekend([Weekday::Fri, Weekday::Sat]);
+
+    let cases = [
+        ("This weekend", date(2010, 1, 1), date(2010, 1, 2)),
+        ("Next weekend", date(2010, 1, 8), date(2010, 1, 9)),
+        ("Last weekend", date(2009, 12, 25), date(2009, 12, 26)),
+        ("This workweek", date(2010, 1, 3), date(2010, 1, 7)),
+        ("Last workweek", date(2009, 12, 27), date(2009, 12, 31)),
+    ];
+
+    for (input, start, end) in cases {
+        let result = parser.parse_period(input, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {start} - {end}");
+        assert_eq!(result, ParseRange::Date(start, end));
+        assert_eq!(parser.parse(input, now).unwrap(), ParseResult::Date(start));
+    }
+
+    // Recurrences on weekdays skip the same weekend.
+    for input in ["Every weekday", "Every workday and Sunday"] {
+        let recurrence = parser.parse_recurrence(input, now).unwrap();
+        assert_eq!(
+            recurrence.pattern,
+            crate::RecurrencePattern::Weekly {
+                interval: 1,
+                weekdays: vec![
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Sun
+                ],
+            },
+            "{input}"
+        );
+    }
+
+    // A weekend that wraps around the end of the week.
+    let parser = crate::Parser::new().weekend([Weekday::Sun, Weekday::Sat]);
+    assert_eq!(
+        parser.parse_period("Next weekend", now).unwrap(),
+        ParseRange::Date(date(2010, 1, 9), date(2010, 1, 10))
+    );
+
+    let all_days = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+    assert!(crate::Parser::new()
+        .weekend([])
+        .parse("This weekend", now)
+        .is_err());
+    assert!(crate::Parser::new()
+        .weekend(all_days)
+        .parse("This workweek", now)
+        .is_err());
+}
+
+#[test]
+fn business_days() {
+    use crate::{BasicCalendar, Parser, Recurrence, RecurrencePattern};
+    use chrono::Weekday;
+
+    let now = now();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let at_noon = |date: NaiveDate| ParseResult::DateTime(date.and_time(now.time()));
+
+    let default_cases = [
+        ("In 3 business days", at_noon(date(2010, 1, 6))),
+        ("In a working day", at_noon(date(2010, 1, 4))),
+        ("5 business days from now", at_noon(date(2010, 1, 8))),
+        ("2 business days ago", at_noon(date(2009, 12, 30))),
+        ("In 1 week and 2 workdays", at_noon(date(2010, 1, 12))),
+        ("Next working day", ParseResult::Date(date(2010, 1, 4))),
+        ("Last business day", ParseResult::Date(date(2009, 12, 31))),
+        ("This business day", ParseResult::Date(date(2010, 1, 1))),
+    ];
+
+    let holiday = BasicCalendar::default().with_holidays([date(2010, 1, 4)]);
+    let parser = Parser::new().business_calendar(holiday);
+    let holiday_cases = [
+        ("In 3 business days", at_noon(date(2010, 1, 7))),
+        ("Next working day", ParseResult::Date(date(2010, 1, 5))),
+    ];
+
+    let middle_east = Parser::new().weekend([Weekday::Fri, Weekday::Sat]);
+    let weekend_cases = [
+        ("In 1 business day", at_noon(date(2010, 1, 3))),
+        ("This business day", ParseResult::Date(date(2010, 1, 3))),
+        ("Last business day", ParseResult::Date(date(2009, 12, 31))),
+    ];
+
+    let cases = default_cases
+        .into_iter()
+        .map(|case| (Parser::new(), case))
+        .chain(holiday_cases.into_iter().map(|case| (parser.clone(), case)))
+        .chain(
+            weekend_cases
+                .into_iter()
+                .map(|case| (middle_east.clone(), case)),
+        );
+
+    for (parser, (input, expected)) in cases {
+        let result = parser.parse(input, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    let recurrence = Parser::new()
+        .parse_recurrence("Every business day", now)
+        .unwrap();
+    assert_eq!(
+        recurrence,
+        Recurrence {
+            start: now,
+            pattern: RecurrencePattern::Weekly {
+                interval: 1,
+                weekdays: vec![
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri
+                ],
+            },
+            time: None,
+            limit: None,
+            week_start: Weekday::Mon,
+        }
+    );
+}
+
+#[test]
+fn holidays() {
+    use crate::{BasicCalendar, Holiday, HolidayCalendar, HolidayRule, Parser, Preference};
+    use chrono::Weekday;
+
+    let now = NaiveDateTime::new(
+        NaiveDate::from_ymd_opt(2010, 6, 1).unwrap(),
+        NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+    );
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    let easter = HolidayRule::Easter { offset: 0 };
+    for (year, expected) in [
+        (1818, date(1818, 3, 22)),
+        (1943, date(1943, 4, 25)),
+        (2000, date(2000, 4, 23)),
+        (2019, date(2019, 4, 21)),
+        (2038, date(2038, 4, 25)),
+    ] {
+        assert_eq!(easter.date_in(year), Some(expected));
+    }
+
+    let last_friday = HolidayRule::NthWeekday {
+        month: 12,
+        ordinal: -1,
+        weekday: Weekday::Fri,
+    };
+    assert_eq!(last_friday.date_in(2010), Some(date(2010, 12, 31)));
+    let fifth_monday = HolidayRule::NthWeekday {
+        month: 2,
+        ordinal: 5,
+        weekday: Weekday::Mon,
+    };
+    assert_eq!(fifth_monday.date_in(2010), None);
+
+    let cases = [
+        (Parser::new(), "Christmas", date(2010, 12, 25)),
+        (Parser::new(), "Xmas", date(2010, 12, 25)),
+        (Parser::new(), "Easter Monday", date(2010, 4, 5)),
+        (Parser::new(), "This Easter", date(2010, 4, 4)),
+        (Parser::new(), "Next Easter", date(2011, 4, 24)),
+        (
+            Parser::new().prefer(Preference::Future),
+            "Easter",
+            date(2011, 4, 24),
+        ),
+        (
+            Parser::new().prefer(Preference::Past),
+            "Christmas",
+            date(2009, 12, 25),
+        ),
+    ];
+    for (parser, input, expected) in cases {
+        let result = parser.parse(input, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, ParseResult::Date(expected));
+    }
+
+    // Custom holidays replace built-in ones with the same name.
+    let parser = Parser::new().holidays(
+        HolidayCalendar::new()
+            .with_holiday(Holiday::new(
+                "Founders Day",
+                HolidayRule::Fixed { month: 3, day: 14 },
+            ))
+            .with_holiday(Holiday::new(
+                "Christmas",
+                HolidayRule::Fixed { month: 1, day: 7 },
+            )),
+    );
+    assert_eq!(
+        parser.parse("The day after founders day", now).unwrap(),
+        ParseResult::Date(date(2010, 3, 15))
+    );
+    assert_eq!(
+        parser.parse("Christmas 2011", now).unwrap(),
+        ParseResult::Date(date(2011, 1, 7))
+    );
+    assert!(parser.parse("Thanksgiving", now).is_err());
+    assert!(Parser::new().parse("{hogswatch}", now).is_err());
+
+    // Holidays as the source of a business calendar. 2010-12-24 is a Friday.
+    let calendar = BasicCalendar::default().with_holiday_calendar(HolidayCalendar::common());
+    let parser = Parser::new().business_calendar(calendar);
+    let christmas_eve = NaiveDateTime::new(date(2010, 12, 23), now.time());
+    assert_eq!(
+        parser.parse("Next business day", christmas_eve).unwrap(),
+        ParseResult::Date(date(2010, 12, 27))
+    );
+    assert_eq!(
+        parser
+            .parse("2 business days before New Years Eve", christmas_eve)
+            .unwrap(),
+        ParseResult::Date(date(2010, 12, 29))
+    );
+}
+
+#[cfg(feature = "holidays-gb")]
+#[test]
+fn holidays_gb() {
+    let now = now();
+
+    assert_eq!(
+        from_human_time("Summer Bank Holiday", now).unwrap(),
+        ParseResult::Date(NaiveDate::from_ymd_opt(2010, 8, 30).unwrap())
+    );
+    assert_eq!(
+        from_human_time("Boxing Day 2021", now).unwrap(),
+        ParseResult::Date(NaiveDate::from_ymd_opt(2021, 12, 26).unwrap())
+    );
+}
+
+#[cfg(feature = "holidays-de")]
+#[test]
+fn holidays_de() {
+    let now = now();
+
+    assert_eq!(
+        from_human_time("Tag der Deutschen Einheit", now).unwrap(),
+        ParseResult::Date(NaiveDate::from_ymd_opt(2010, 10, 3).unwrap())
+    );
+}
+
+#[test]
+fn anchors() {
+    use crate::{Anchor, Parser};
+    use chrono::Days;
+
+    let now = now();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let sprint_end = Anchor::computed(|now| now.date() + Days::new(13));
+    let parser = Parser::new()
+        .anchor("Release Day", date(2010, 3, 1))
+        .anchor("sprint end", sprint_end.clone())
+        .anchor("end of sprint", sprint_end)
+        .anchor("Christmas", date(2010, 12, 20));
+
+    let cases = [
+        ("Release day", ParseResult::Date(date(2010, 3, 1))),
+        ("sprint end", ParseResult::Date(date(2010, 1, 14))),
+        ("End of sprint", ParseResult::Date(date(2010, 1, 14))),
+        (
+            "2 days before sprint end at 10:00",
+            ParseResult::DateTime(date(2010, 1, 12).and_hms_opt(10, 0, 0).unwrap()),
+        ),
+        (
+            "13:00 release day",
+            ParseResult::DateTime(date(2010, 3, 1).and_hms_opt(13, 0, 0).unwrap()),
+        ),
+        (
+            "The day after Christmas",
+            ParseResult::Date(date(2010, 12, 21)),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        let result = parser.parse(input, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    let range = parser.parse("From today until release day", now).unwrap();
+    assert_eq!(
+        range,
+        ParseResult::Range(ParseRange::Date(date(2010, 1, 1), date(2010, 3, 1)))
+    );
+
+    assert!(parser.parse("Next release day", now).is_err());
+    assert!(parser.parse("Release day 2011", now).is_err());
+    assert!(Parser::new().parse("Release day", now).is_err());
+}
+
+#[test]
+fn custom_locale() {
+    use crate::{Locale, Parser, Unit};
+    use chrono::{Month, Weekday};
+
+    let now = now();
+    let dutch = Locale::new("nl")
+        .month(Month::March, ["maart", "mrt"])
+        .weekday(Weekday::Mon, ["maandag"])
+        .weekday(Weekday::Fri, ["vrijdag"])
+        .unit(Unit::Day, ["dag", "dagen"])
+        .unit(Unit::Hour, ["uur"])
+        .word("tomorrow", ["morgen"])
+        .word("overmorrow", ["overmorgen"])
+        .word("next", ["volgende"])
+        .word("last", ["vorige"])
+        .word("in", ["over"])
+        .word("ago", ["geleden"])
+        .word("at", ["om"])
+        .word("every", ["elke"]);
+    let parser = Parser::new().locale(dutch).anchor(
+        "vrijdagborrel",
+        NaiveDate::from_ymd_opt(2010, 2, 5).unwrap(),
+    );
+    let english = Parser::new();
+
+    let cases = [
+        ("Morgen", "Tomorrow"),
+        ("Overmorgen om 9:30", "Overmorrow at 9:30"),
+        ("Volgende vrijdag om 17:00", "Next Friday at 17:00"),
+        ("Vorige maandag", "Last Monday"),
+        ("Over 3 dagen", "In 3 days"),
+        ("2 uur geleden", "2 hours ago"),
+        ("7 mrt 2011", "7 March 2011"),
+        ("Volgende week", "Next week"),
+    ];
+
+    for (input, equivalent) in cases {
+        let result = parser.parse(input, now).unwrap();
+        let expected = english.parse(equivalent, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    // Anchors are matched before translating, so their names can contain translated words.
+    assert_eq!(
+        parser.parse("Vrijdagborrel", now).unwrap(),
+        ParseResult::Date(NaiveDate::from_ymd_opt(2010, 2, 5).unwrap())
+    );
+    assert_eq!(
+        parser
+            .parse_recurrence("Elke maandag om 9:00", now)
+            .unwrap(),
+        english
+            .parse_recurrence("Every Monday at 9:00", now)
+            .unwrap()
+    );
+    // Holidays are only known by the names they were registered with.
+    assert!(parser.parse("Kerstmis", now).is_err());
+    assert_eq!(english.options().locale, Locale::english());
+}
+
+#[test]
+fn german() {
+    use crate::{Locale, Parser};
+
+    let now = now();
+    let german = Parser::new().locale(Locale::german());
+    let english = Parser::new();
+
+    let cases = [
+        ("Heute", "Today"),
+        ("Morgen", "Tomorrow"),
+        ("Übermorgen", "Overmorrow"),
+        ("Gestern 18:30", "Yesterday 18:30"),
+        ("Vorgestern", "The day before yesterday"),
+        ("Nächsten Freitag um 14 Uhr", "Next Friday at 14:00"),
+        ("Nächste Woche Sonntag", "Next week Sunday"),
+        ("Letzten Dienstag", "Last Tuesday"),
+        ("Diesen Samstag um 9:30 Uhr", "This Saturday at 9:30"),
+        ("Vor 3 Tagen", "3 days ago"),
+        ("Vor einer Stunde", "An hour ago"),
+        ("Vor 2 Stunden und 5 Minuten", "2 hours and 5 minutes ago"),
+        ("In 3 Stunden", "In 3 hours"),
+        ("In einer Woche", "In a week"),
+        ("In 2 Werktagen", "In 2 business days"),
+        ("Morgen früh", "Tomorrow morning"),
+        ("Heute Morgen", "This morning"),
+        ("Morgen Abend", "Tomorrow evening"),
+        ("24.12.2025", "2025-12-24"),
+        ("Am 24.12. um 10 Uhr", "10:00 24 December"),
+        ("3. März 2026", "3 March 2026"),
+        ("Im Mai", "In May"),
+        ("Letzten Dezember", "Last December"),
+        ("Nächstes Wochenende", "Next weekend"),
+        ("Nächsten Monat", "Next month"),
+        ("Jetzt", "Now"),
+        ("2 Tage nach Ostern", "2 days after Easter"),
+        ("Vor Weihnachten", "Before Christmas"),
+        ("Von Montag bis Freitag", "From Monday to Friday"),
+        ("Zwischen 9 und 17 Uhr", "Between 9 and 17:00"),
+    ];
+
+    for (input, equivalent) in cases {
+        let result = german.parse(input, now).unwrap();
+        let expected = english.parse(equivalent, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    assert_eq!(
+        german
+            .parse_recurrence("Jeden zweiten Dienstag um 17:30", now)
+            .unwrap(),
+        english
+            .parse_recurrence("Every other Tuesday at 17:30", now)
+            .unwrap()
+    );
+
+    // Hours, minutes and seconds name no date, in German just as in English.
+    assert!(matches!(
+        german.parse("Nächste Stunde", now),
+        Err(ParseError::ProccessingErrors(errors))
+            if matches!(errors[..], [ProcessingError::RelativeTimeUnit { .. }])
+    ));
+
+    // Dotted dates are checked like any other, and a month that does not exist is no exception.
+    for input in ["31.02.", "13.13.", "0.0."] {
+        let result = german.parse(input, now);
+        println!("Input: {input}\nResult: {result:?}\nExpected: InvalidDate");
+        assert!(matches!(
+            result,
+            Err(ParseError::ProccessingErrors(errors))
+                if matches!(errors[..], [ProcessingError::InvalidDate { .. }])
+        ));
+    }
+}
+
+#[test]
+fn spanish() {
+    use crate::{Locale, Parser};
+
+    let now = now();
+    let spanish = Parser::new().locale(Locale::spanish());
+    let english = Parser::new();
+
+    let cases = [
+        ("Hoy", "Today"),
+        ("Mañana", "Tomorrow"),
+        ("Pasado mañana", "Overmorrow"),
+        ("Ayer a las 18:30", "Yesterday at 18:30"),
+        ("Anteayer", "The day before yesterday"),
+        ("El próximo viernes a las 5", "Next Friday at 5:00"),
+        ("El viernes que viene", "Next Friday"),
+        ("El martes pasado", "Last Tuesday"),
+        ("Este sábado a las 9:30", "This Saturday at 9:30"),
+        ("Hace 3 días", "3 days ago"),
+        ("Hace una hora", "An hour ago"),
+        ("Hace 2 horas y 5 minutos", "2 hours and 5 minutes ago"),
+        ("En 3 horas", "In 3 hours"),
+        ("Dentro de una semana", "In a week"),
+        ("En 2 días hábiles", "In 2 business days"),
+        ("Mañana por la mañana", "Tomorrow morning"),
+        ("Esta tarde", "This afternoon"),
+        ("24 de diciembre de 2025", "24 December 2025"),
+        ("El 3 de marzo", "3 March"),
+        ("En mayo", "In May"),
+        ("La semana que viene", "Next week"),
+        ("El mes pasado", "Last month"),
+        ("El próximo fin de semana", "Next weekend"),
+        ("Ahora", "Now"),
+        ("2 días después de Navidad", "2 days after Christmas"),
+        (
+            "Desde el lunes hasta el viernes",
+            "From Monday until Friday",
+        ),
+        ("Entre las 9 y las 17:00", "Between 9 and 17:00"),
+        // Accents are optional.
+        ("Pasado manana", "Overmorrow"),
+        ("Hace 3 dias", "3 days ago"),
+    ];
+
+    for (input, equivalent) in cases {
+        let result = spanish.parse(input, now).unwrap();
+        let expected = english.parse(equivalent, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    assert_eq!(
+        spanish
+            .parse_recurrence("Todos los lunes a las 9:00", now)
+            .unwrap(),
+        english
+            .parse_recurrence("Every Monday at 9:00", now)
+            .unwrap()
+    );
+
+    // Hours, minutes and seconds name no date, in Spanish just as in English.
+    assert!(matches!(
+        spanish.parse("La próxima hora", now),
+        Err(ParseError::ProccessingErrors(errors))
+            if matches!(errors[..], [ProcessingError::RelativeTimeUnit { .. }])
+    ));
+}
+
+#[test]
+fn french() {
+    use crate::{Locale, Parser};
+
+    let now = now();
+    let french = Parser::new().locale(Locale::french());
+    let english = Parser::new();
+
+    let cases = [
+        ("Aujourd'hui", "Today"),
+        ("Demain", "Tomorrow"),
+        ("Après-demain", "Overmorrow"),
+        ("Hier à 18h30", "Yesterday at 18:30"),
+        ("Avant-hier", "The day before yesterday"),
+        ("Vendredi prochain à 17h", "Next Friday at 17:00"),
+        ("Mardi dernier", "Last Tuesday"),
+        ("Ce samedi à 9:30", "This Saturday at 9:30"),
+        ("Il y a 3 jours", "3 days ago"),
+        ("Il y a une heure", "An hour ago"),
+        ("Il y a 2 heures et 5 minutes", "2 hours and 5 minutes ago"),
+        ("Dans 3 heures", "In 3 hours"),
+        ("Dans une semaine", "In a week"),
+        ("Dans 2 jours ouvrés", "In 2 business days"),
+        ("Demain matin", "Tomorrow morning"),
+        ("Ce soir", "This evening"),
+        ("Le 24 décembre 2025", "24 December 2025"),
+        ("En mai", "In May"),
+        ("La semaine prochaine", "Next week"),
+        ("Le mois dernier", "Last month"),
+        ("Le week-end prochain", "Next weekend"),
+        ("Maintenant", "Now"),
+        ("2 jours après Noël", "2 days after Christmas"),
+        ("Noël prochain", "Next Christmas"),
+        ("De lundi jusqu'à vendredi", "From Monday until Friday"),
+        ("Entre 9 et 17h", "Between 9 and 17:00"),
+        // Accents are optional.
+        ("Apres-demain", "Overmorrow"),
+        ("Le 24 decembre 2025", "24 December 2025"),
+    ];
+
+    for (input, equivalent) in cases {
+        let result = french.parse(input, now).unwrap();
+        let expected = english.parse(equivalent, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    assert_eq!(
+        french.parse_recurrence("Chaque lundi à 9h", now).unwrap(),
+        english
+            .parse_recurrence("Every Monday at 9:00", now)
+            .unwrap()
+    );
+
+    // Hours, minutes and seconds name no date, in French just as in English.
+    assert!(matches!(
+        french.parse("Heure prochaine", now),
+        Err(ParseError::ProccessingErrors(errors))
+            if matches!(errors[..], [ProcessingError::RelativeTimeUnit { .. }])
+    ));
+}
+
+#[test]
+fn normalization() {
+    use crate::Parser;
+
+    let now = now();
+    let parser = Parser::new();
+
+    let cases = [
+        ("Next Friday 17:00\n", "Next Friday 17:00"),
+        ("\tNext\tFriday\r\n", "Next Friday"),
+        ("Next\u{a0}Friday\u{2009}17:00", "Next Friday 17:00"),
+        ("In  3   days", "In 3 days"),
+        ("Ｉｎ ３ ｄａｙｓ", "In 3 days"),
+        ("Today １７：３０", "Today 17:30"),
+        ("In ٣ days", "In 3 days"),
+        ("New Year\u{2019}s Eve", "New Year's Eve"),
+        ("9:00\u{2013}17:00", "9:00-17:00"),
+        ("To\u{200b}morrow", "Tomorrow"),
+        ("TOMORROW", "Tomorrow"),
+    ];
+
+    for (input, equivalent) in cases {
+        let result = parser.parse(input, now).unwrap();
+        let expected = parser.parse(equivalent, now).unwrap();
+        println!("Input: {input:?}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    let input = "  Über\u{a0}morgen\n";
+    let normalized = parser.normalize(input);
+    assert_eq!(normalized.as_str(), "über morgen");
+    assert_eq!(normalized.original_range(0..5), 2..7);
+    assert_eq!(&input[normalized.original_range(6..12)], "morgen");
+    assert_eq!(normalized.original_offset(12), input.len() - 1);
+
+    // Letters are folded fully, not just lowercased.
+    let input = "Straße ﬀ";
+    let normalized = parser.normalize(input);
+    assert_eq!(normalized.as_str(), "strasse ff");
+    assert_eq!(normalized.as_str(), parser.normalize("STRASSE FF").as_str());
+    assert_eq!(&input[normalized.original_range(4..6)], "ß");
+    let parser = Parser::new().anchor("Straßenfest", NaiveDate::from_ymd_opt(2010, 7, 3).unwrap());
+    assert_eq!(
+        parser.parse("STRASSENFEST", now).unwrap(),
+        ParseResult::Date(NaiveDate::from_ymd_opt(2010, 7, 3).unwrap())
+    );
+
+    let parser = Parser::new()
+        .strip_accents(true)
+        .anchor("Fête", NaiveDate::from_ymd_opt(2010, 7, 14).unwrap());
+    assert_eq!(parser.normalize("Über").as_str(), "uber");
+    assert_eq!(
+        parser.parse("Fete", now).unwrap(),
+        ParseResult::Date(NaiveDate::from_ymd_opt(2010, 7, 14).unwrap())
+    );
+    assert_eq!(
+        parser.parse("FÊTE", now).unwrap(),
+        ParseResult::Date(NaiveDate::from_ymd_opt(2010, 7, 14).unwrap())
+    );
+
+    // Letters outside of Latin-1 and accents that are already split off their letter.
+    assert_eq!(
+        parser.normalize("Łódź Őr Şah Čas").as_str(),
+        "lodz or sah cas"
+    );
+    let input = "Fe\u{302}te";
+    let normalized = parser.normalize(input);
+    assert_eq!(normalized.as_str(), "fete");
+    assert_eq!(&input[normalized.original_range(1..3)], "e\u{302}t");
+    assert_eq!(
+        parser.parse(input, now).unwrap(),
+        ParseResult::Date(NaiveDate::from_ymd_opt(2010, 7, 14).unwrap())
+    );
+    let german = Parser::new().locale(crate::Locale::german());
+    assert_eq!(
+        german.parse("3. Ma\u{308}rz", now).unwrap(),
+        german.parse("3. März", now).unwrap()
+    );
+}
+
+#[test]
+fn syntax_errors() {
+    use crate::{ParseError, Parser};
+
+    let now = now();
+    let syntax_error = |parser: &Parser, input: &str| match parser.parse(input, now) {
+        Err(ParseError::InvalidFormat(error)) => error,
+        result => panic!("Expected a syntax error for {input:?}, got {result:?}"),
+    };
+    let parser = Parser::new();
+
+    // Input, span, line, column and one of the expected descriptions.
+    let cases = [
+        ("Blah", 0..4, 1, 1, "a date or time"),
+        ("Next blah", 5..9, 1, 6, "a weekday"),
+        ("In 3 blahs", 5..10, 1, 6, "a unit of time"),
+        ("  In\t3 blahs\n", 7..12, 1, 8, "a unit of time"),
+        ("In 3\nblahs", 5..10, 2, 1, "a unit of time"),
+        ("Ｎｅｘｔ blah", 13..17, 1, 6, "a weekday"),
+        ("Q5", 0..2, 1, 1, "a quarter from 1 to 4"),
+        ("Today garbage", 6..13, 1, 7, "the end of the input"),
+        ("Tomorrow blah", 9..13, 1, 10, "the end of the input"),
+        (
+            "Tomorrow at 10 in room 4",
+            15..17,
+            1,
+            16,
+            "the end of the input",
+        ),
+    ];
+
+    for (input, span, line, column, expected) in cases {
+        let error = syntax_error(&parser, input);
+        println!("Input: {input:?}\nResult: {error}\nExpected: {span:?}, {line}:{column}");
+        assert_eq!(error.input(), input);
+        assert_eq!(error.span(), span);
+        assert_eq!(error.found(), Some(&input[span]));
+        assert_eq!((error.line(), error.column()), (line, column));
+        assert!(error.expected().iter().any(|e| e == expected));
+    }
+
+    let error = syntax_error(&parser, "In 3");
+    assert_eq!(error.span(), 4..4);
+    assert_eq!(error.found(), None);
+
+    assert_eq!(
+        syntax_error(&parser, "In 3 blahs").to_string(),
+        "Could not match input to any known format at line 1, column 6: expected a unit of time, \
+         found \"blahs\"\n  |\n1 | In 3 blahs\n  |      ^^^^^"
+    );
+
+    // Positions refer to the untranslated input.
+    let german = Parser::new().locale(crate::Locale::german());
+    let error = syntax_error(&german, "Nächsten Quatsch");
+    assert_eq!(error.found(), Some("Quatsch"));
+    assert_eq!(error.column(), 10);
+}
+
+#[test]
+fn typos() {
+    use crate::{Locale, ParseError, Parser};
+
+    let now = now();
+    let parser = Parser::new();
+    let autocorrect = Parser::new().autocorrect(true);
+
+    // Input and what it suggests.
+    let cases = [
+        ("Tommorow", vec!["tomorrow"]),
+        ("Next wendesday", vec!["wednesday"]),
+        ("3 minuts ago", vec!["minute", "minutes"]),
+        ("Yesteday at 10:00", vec!["yesterday"]),
+        ("Christmass", vec!["christmas"]),
+        ("Next blah", vec![]),
+        ("Tomorrow at 10 in room 4", vec![]),
+        // Misspellings that start with a valid word are errors too.
+        ("Feburary 3", vec!["february"]),
+        ("In 3 minuts", vec!["minute", "minutes"]),
+        ("In 3 dayz", vec!["day", "days"]),
+        ("Next mondya", vec!["monday"]),
+        ("Last Tueday", vec!["tuesday", "sunday", "today"]),
+    ];
+
+    for (input, expected) in cases {
+        let Err(ParseError::InvalidFormat(error)) = parser.parse(input, now) else {
+            panic!("Expected a syntax error for {input:?}");
+        };
+        println!(
+            "Input: {input}\nResult: {:?}\nExpected: {expected:?}",
+            error.suggestions()
+        );
+        assert_eq!(error.suggestions(), expected);
+    }
+
+    // The error covers the whole misspelled word.
+    let Err(ParseError::InvalidFormat(error)) = parser.parse("In 3 minuts", now) else {
+        panic!("Expected a syntax error for \"In 3 minuts\"");
+    };
+    assert_eq!(error.found(), Some("minuts"));
+
+    // Misspelled input and what it is corrected to.
+    let cases = [
+        ("Tommorow", "Tomorrow"),
+        ("Next wendesday", "Next Wednesday"),
+        ("3 minuts ago", "3 minutes ago"),
+        ("Yesteday at 10:00", "Yesterday at 10:00"),
+        ("3 Feburary", "3 February"),
+        ("Feburary 2025", "February 2025"),
+        ("In 3 dayz", "In 3 days"),
+        ("Next mondya", "Next Monday"),
+        ("Last Tueday", "Last Tuesday"),
+        ("Nxt Fridya at 10:00", "Next Friday at 10:00"),
+        ("Christmass", "Christmas"),
+    ];
+
+    for (input, equivalent) in cases {
+        let result = autocorrect.parse(input, now).unwrap();
+        let expected = parser.parse(equivalent, now).unwrap();
+        println!("Input: {input}\nResult: {result}\nExpected: {expected}");
+        assert_eq!(result, expected);
+    }
+
+    // Words that are equally close to several others are not corrected.
+    let date = NaiveDate::from_ymd_opt(2010, 2, 1).unwrap();
+    let anchors = autocorrect
+        .clone()
+        .anchor("Kickoff", date)
+        .anchor("Kickout", date);
+    let Err(ParseError::InvalidFormat(error)) = anchors.parse("Kickouf", now) else {
+        panic!("Expected a syntax error for \"Kickouf\"");
+    };
+    assert_eq!(error.suggestions(), ["kickoff", "kickout"]);
+    assert!(error
+        .to_string()
+        .ends_with("\n  = help: did you mean \"kickoff\" or \"kickout\"?"));
+    // Neither are words too far off.
+    assert!(autocorrect.parse("Tmrw", now).is_err());
+    assert!(autocorrect
+        .clone()
+        .max_typo_distance(1)
+        .parse("Tommorow", now)
+        .is_err());
+
+    assert_eq!(
+        autocorrect
+            .parse_recurrence("Evrey Monday at 9:00", now)
+            .unwrap(),
+        parser
+            .parse_recurrence("Every Monday at 9:00", now)
+            .unwrap()
+    );
+
+    let german = Parser::new().locale(Locale::german()).autocorrect(true);
+    assert_eq!(
+        german.parse("Übermorgn", now).unwrap(),
+        parser.parse("Overmorrow", now).unwrap()
+    );
+}
+
+#[test]
+fn extraction() {
+    use crate::{extract_human_times, ExtractedValue, Locale, Parser};
+
+    let now = now();
+    let time = |input: &str| ExtractedValue::Time(from_human_time(input, now).unwrap());
+
+    // Text and the expressions found in it.
+    let cases = [
+        (
+            "Let's meet next Friday at 15:00 in room 4 for 2 hours.",
+            vec![
+                (11..31, "next Friday at 15:00", time("next Friday at 15:00")),
+                (
+                    46..53,
+                    "2 hours",
+                    ExtractedValue::Duration(chrono::Duration::hours(2)),
+                ),
+            ],
+        ),
+        (
+            "May I check the monitor tomorrow? Otherwise in 3 days, or on 3 May.",
+            vec![
+                (24..32, "tomorrow", time("tomorrow")),
+                (44..53, "in 3 days", time("in 3 days")),
+                (61..66, "3 May", time("3 May")),
+            ],
+        ),
+        (
+            "We're off  the day after Christmas, back 2 weeks later.",
+            vec![
+                (
+                    11..34,
+                    "the day after Christmas",
+                    time("the day after Christmas"),
+                ),
+                (
+                    41..48,
+                    "2 weeks",
+                    ExtractedValue::Duration(chrono::Duration::weeks(2)),
+                ),
+            ],
+        ),
+        (
+            "Wait 1 hour and 30 minutes, then 2h 30m, or a day.",
+            vec![
+                (
+                    5..26,
+                    "1 hour and 30 minutes",
+                    ExtractedValue::Duration(chrono::Duration::minutes(90)),
+                ),
+                (
+                    33..39,
+                    "2h 30m",
+                    ExtractedValue::Duration(chrono::Duration::minutes(150)),
+                ),
+                (
+                    44..49,
+                    "a day",
+                    ExtractedValue::Duration(chrono::Duration::days(1)),
+                ),
+            ],
+        ),
+        // The longest match that ends at the end of a word wins, even if a longer one runs into
+        // the middle of the next word.
+        (
+            "Open from Monday to Friday afternoons.",
+            vec![(
+                5..26,
+                "from Monday to Friday",
+                time("from Monday to Friday"),
+            )],
+        ),
+        // Sub-day units name no date, and durations that do not fit are left out.
+        ("Back within the next hour, at the last minute.", vec![]),
+        ("uptime: 3000000000 hours", vec![]),
+        ("Nothing to see here, Sun is out. I am fine", vec![]),
+        ("", vec![]),
+    ];
+
+    for (text, expected) in cases {
+        let result: Vec<_> = extract_human_times(text, now)
+            .into_iter()
+            .map(|found| (found.span, found.text, found.value))
+            .collect();
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(span, found, value)| (span, found.to_string(), value))
+            .collect();
+        println!("Input: {text}\nResult: {result:?}\nExpected: {expected:?}");
+        assert_eq!(result, expected);
+    }
+
+    // Spans refer to the text as it was given, before it was translated.
+    let german = Parser::new().locale(Locale::german());
+    let text = "Das Treffen ist nächsten Freitag um 15:00, nicht übermorgen.";
+    let found = german.extract(text, now);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].text, "nächsten Freitag um 15:00");
+    assert_eq!(found[0].span, 16..42);
+    assert_eq!(found[0].value, time("next Friday at 15:00"));
+    assert_eq!(found[1].text, "übermorgen");
+    assert_eq!(&text[found[1].span.clone()], "übermorgen");
+    assert_eq!(found[1].value, time("overmorrow"));
+}
+
+#[test]
+fn sub_day_units_and_large_durations() {
+    let now = now();
+    let errors = |input: &str| match from_human_time(input, now) {
+        Err(ParseError::ProccessingErrors(errors)) => errors,
+        result => panic!("Expected processing errors for {input:?}, got {result:?}"),
+    };
+
+    for input in ["Next hour", "This minute", "Last second", "Hour next"] {
+        assert!(matches!(
+            errors(input)[..],
+            [ProcessingError::RelativeTimeUnit { .. }]
+        ));
+    }
+    assert!(matches!(
+        errors("In 3000000000 hours")[..],
+        [ProcessingError::AddToDate {
+            count: 3000000000,
+            ..
+        }]
+    ));
+    assert!(matches!(
+        errors("3000000000 hours ago")[..],
+        [ProcessingError::SubtractFromDate {
+            count: 3000000000,
+            ..
+        }]
+    ));
+}